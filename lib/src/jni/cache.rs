@@ -0,0 +1,173 @@
+//! Classes, constructors and fields which are used for every row and parameter, looked up once when the library is loaded.
+//! Looking them up through the JNIEnv on every use dominates the time it takes to convert large results.
+
+use std::ffi::c_void;
+use std::sync::OnceLock;
+use jni::{JavaVM, JNIEnv};
+use jni::objects::{GlobalRef, JFieldID, JMethodID, JStaticMethodID};
+use jni::sys::{jfieldID, jint, jmethodID, JNI_ERR, JNI_VERSION_1_8};
+
+static CACHE: OnceLock<Cache> = OnceLock::new();
+
+/// A method ID. Method IDs stay valid for as long as their class is loaded, on every thread
+#[derive(Clone, Copy)]
+pub struct MethodId(jmethodID);
+
+/// A static method ID, valid like a [MethodId]
+#[derive(Clone, Copy)]
+pub struct StaticMethodId(jmethodID);
+
+/// A field ID. Field IDs stay valid for as long as their class is loaded, on every thread
+#[derive(Clone, Copy)]
+pub struct FieldId(jfieldID);
+
+// SAFETY: See the documentation of the types, the IDs are plain identifiers which are not tied to a thread
+unsafe impl Send for MethodId {}
+unsafe impl Sync for MethodId {}
+unsafe impl Send for StaticMethodId {}
+unsafe impl Sync for StaticMethodId {}
+unsafe impl Send for FieldId {}
+unsafe impl Sync for FieldId {}
+
+impl MethodId {
+    pub fn get(self) -> JMethodID<'static> {
+        JMethodID::from(self.0)
+    }
+}
+
+impl StaticMethodId {
+    pub fn get(self) -> JStaticMethodID<'static> {
+        JStaticMethodID::from(self.0)
+    }
+}
+
+impl FieldId {
+    pub fn get(self) -> JFieldID<'static> {
+        JFieldID::from(self.0)
+    }
+}
+
+#[allow(non_snake_case)]   // Named after the Java classes and their members, like the functions in util::Java
+pub struct Cache {
+    pub String: GlobalRef,
+    pub Double: GlobalRef,
+    pub Boolean: GlobalRef,
+    pub Object: GlobalRef,
+    pub byte_array: GlobalRef,
+    pub Long: GlobalRef,
+    pub Integer: GlobalRef,
+    pub Class: GlobalRef,
+    pub SqlRow: GlobalRef,
+    pub ExecuteResult: GlobalRef,
+    pub LocalDate: GlobalRef,
+    pub LocalDateTime: GlobalRef,
+    pub Instant: GlobalRef,
+    pub Duration: GlobalRef,
+    pub LocalTime: GlobalRef,
+    pub OffsetTime: GlobalRef,
+    pub ZoneOffset: GlobalRef,
+
+    pub Long_new: MethodId,
+    pub Integer_new: MethodId,
+    pub Double_new: MethodId,
+    pub SqlRow_new: MethodId,
+    pub ExecuteResult_new: MethodId,
+    pub Enum_name: MethodId,
+    pub LocalDate_of: StaticMethodId,
+    pub LocalDateTime_of: StaticMethodId,
+    pub Instant_ofEpochSecond: StaticMethodId,
+    pub Duration_ofSeconds: StaticMethodId,
+    pub LocalTime_ofNanoOfDay: StaticMethodId,
+    pub OffsetTime_of: StaticMethodId,
+    pub ZoneOffset_ofTotalSeconds: StaticMethodId,
+
+    pub SqlParameter_type: FieldId,
+    pub SqlParameter_bytesVal: FieldId,
+    pub SqlParameter_longVal: FieldId,
+    pub SqlParameter_floatVal: FieldId,
+    pub SqlParameter_doubleVal: FieldId,
+    pub SqlParameter_nanoVal: FieldId,
+}
+
+impl Cache {
+    fn new(env: JNIEnv) -> jni::errors::Result<Self> {
+        let class = |name: &str| env.find_class(name).and_then(|c| env.new_global_ref(c));
+
+        let cache = Self {
+            String: class("java/lang/String")?,
+            Double: class("java/lang/Double")?,
+            Boolean: class("java/lang/Boolean")?,
+            Object: class("java/lang/Object")?,
+            byte_array: class("[B")?,
+            Long: class("java/lang/Long")?,
+            Integer: class("java/lang/Integer")?,
+            Class: class("java/lang/Class")?,
+            SqlRow: class("dev/array21/jdbd/datatypes/SqlRow")?,
+            ExecuteResult: class("dev/array21/jdbd/datatypes/ExecuteResult")?,
+            LocalDate: class("java/time/LocalDate")?,
+            LocalDateTime: class("java/time/LocalDateTime")?,
+            Instant: class("java/time/Instant")?,
+            Duration: class("java/time/Duration")?,
+            LocalTime: class("java/time/LocalTime")?,
+            OffsetTime: class("java/time/OffsetTime")?,
+            ZoneOffset: class("java/time/ZoneOffset")?,
+
+            Long_new: method(env, "java/lang/Long", "<init>", "(J)V")?,
+            Integer_new: method(env, "java/lang/Integer", "<init>", "(I)V")?,
+            Double_new: method(env, "java/lang/Double", "<init>", "(D)V")?,
+            SqlRow_new: method(env, "dev/array21/jdbd/datatypes/SqlRow", "<init>", "([Ljava/lang/String;[Ljava/lang/Object;[Ljava/lang/Class;)V")?,
+            ExecuteResult_new: method(env, "dev/array21/jdbd/datatypes/ExecuteResult", "<init>", "(JLjava/lang/Long;ILjava/lang/String;)V")?,
+            Enum_name: method(env, "java/lang/Enum", "name", "()Ljava/lang/String;")?,
+            LocalDate_of: static_method(env, "java/time/LocalDate", "of", "(III)Ljava/time/LocalDate;")?,
+            LocalDateTime_of: static_method(env, "java/time/LocalDateTime", "of", "(IIIIIII)Ljava/time/LocalDateTime;")?,
+            Instant_ofEpochSecond: static_method(env, "java/time/Instant", "ofEpochSecond", "(JJ)Ljava/time/Instant;")?,
+            Duration_ofSeconds: static_method(env, "java/time/Duration", "ofSeconds", "(JJ)Ljava/time/Duration;")?,
+            LocalTime_ofNanoOfDay: static_method(env, "java/time/LocalTime", "ofNanoOfDay", "(J)Ljava/time/LocalTime;")?,
+            OffsetTime_of: static_method(env, "java/time/OffsetTime", "of", "(Ljava/time/LocalTime;Ljava/time/ZoneOffset;)Ljava/time/OffsetTime;")?,
+            ZoneOffset_ofTotalSeconds: static_method(env, "java/time/ZoneOffset", "ofTotalSeconds", "(I)Ljava/time/ZoneOffset;")?,
+
+            SqlParameter_type: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "type", "Ldev/array21/jdbd/datatypes/SqlParameterType;")?,
+            SqlParameter_bytesVal: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "bytesVal", "[B")?,
+            SqlParameter_longVal: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "longVal", "J")?,
+            SqlParameter_floatVal: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "floatVal", "F")?,
+            SqlParameter_doubleVal: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "doubleVal", "D")?,
+            SqlParameter_nanoVal: field(env, "dev/array21/jdbd/datatypes/SqlParameter", "nanoVal", "I")?,
+        };
+
+        Ok(cache)
+    }
+}
+
+fn method(env: JNIEnv, class: &str, name: &str, sig: &str) -> jni::errors::Result<MethodId> {
+    env.get_method_id(class, name, sig).map(|m| MethodId(m.into_inner()))
+}
+
+fn static_method(env: JNIEnv, class: &str, name: &str, sig: &str) -> jni::errors::Result<StaticMethodId> {
+    env.get_static_method_id(class, name, sig).map(|m| StaticMethodId(m.into_inner()))
+}
+
+fn field(env: JNIEnv, class: &str, name: &str, sig: &str) -> jni::errors::Result<FieldId> {
+    env.get_field_id(class, name, sig).map(|f| FieldId(f.into_inner()))
+}
+
+/// Get the cache. The JVM always calls `JNI_OnLoad` before any native method, and refuses to load the library if it fails
+pub fn cache() -> &'static Cache {
+    CACHE.get().expect("JNI_OnLoad has not been called")
+}
+
+/// Called by the JVM when the library is loaded. If the lookups fail, the pending exception is thrown from `System.load`
+#[no_mangle]
+pub extern "system" fn JNI_OnLoad(vm: JavaVM, _reserved: *mut c_void) -> jint {
+    let env = match vm.get_env() {
+        Ok(e) => e,
+        Err(_) => return JNI_ERR
+    };
+
+    match Cache::new(env) {
+        Ok(cache) => {
+            let _ = CACHE.set(cache);
+            JNI_VERSION_1_8
+        },
+        Err(_) => JNI_ERR
+    }
+}
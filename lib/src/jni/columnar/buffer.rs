@@ -0,0 +1,36 @@
+use jni::JNIEnv;
+use jni::objects::JClass;
+use jni::sys::{jlong, jobject};
+use std::ptr::null_mut;
+use crate::jni::columnar::ColumnarBuffer;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      ColumnarBuffer
+ * - Method:     getNative
+ * - Signature:  `(J)Ljava/nio/ByteBuffer;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_ColumnarBuffer_getNative(env: JNIEnv, _class: JClass<'_>, handle: jlong) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let buffer = match get_handle::<ColumnarBuffer>(handle) {
+            Ok(b) => b,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get buffer: {}", e));
+                return null_mut();
+            }
+        };
+
+        // The ByteBuffer points into the Vec, which stays in the registry until the buffer is freed
+        let mut guard = buffer.lock().unwrap_or_else(|e| e.into_inner());
+        match env.new_direct_byte_buffer(guard.as_mut_slice()) {
+            Ok(b) => b.into_inner(),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create direct ByteBuffer: {:?}", e));
+                null_mut()
+            }
+        }
+    })
+}
@@ -0,0 +1,21 @@
+use jni::JNIEnv;
+use jni::objects::JClass;
+use jni::sys::jlong;
+use crate::jni::columnar::ColumnarBuffer;
+use crate::jni::handle::remove_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      ColumnarBuffer
+ * - Method:     freeNative
+ * - Signature:  `(J)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_ColumnarBuffer_freeNative(env: JNIEnv, _class: JClass<'_>, handle: jlong) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        if let Err(e) = remove_handle::<ColumnarBuffer>(handle) {
+            throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to free buffer: {}", e));
+        }
+    })
+}
@@ -0,0 +1,168 @@
+//! Query results encoded as one binary buffer of columns, which Java decodes into typed arrays with `ColumnarResult.decode`.
+//! This avoids creating a Java object for every row and value.
+//!
+//! All numbers are little endian. The buffer starts with the number of columns and the number of rows, both as i32, followed by every column:
+//! - The length of the column name as i32, followed by the name encoded as UTF-8
+//! - The kind of the column as u8, see [ColumnKind]
+//! - A null bitmap of `(rows + 7) / 8` bytes. Bit `row % 8` of byte `row / 8` is set if the value in that row is NULL
+//! - For LONG and DOUBLE columns, an i64 or f64 for every row. The value of a NULL is 0
+//! - For STRING and BYTES columns, `rows + 1` offsets as i32, followed by the values. A value runs from its offset up to the offset of the next row
+
+use std::sync::Mutex;
+
+mod buffer;
+mod free;
+
+/// An encoded result, backing a ColumnarBuffer until it has been decoded
+pub type ColumnarBuffer = Mutex<Vec<u8>>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ColumnKind {
+    Long = 0,
+    Double = 1,
+    String = 2,
+    Bytes = 3,
+}
+
+enum Values {
+    Long(Vec<i64>),
+    Double(Vec<f64>),
+    /// The offsets of the values, followed by the end of the last value, and the values themselves
+    Bytes(Vec<i32>, Vec<u8>),
+}
+
+/// A column which is built up one row at a time
+pub struct Column {
+    name: String,
+    kind: ColumnKind,
+    nulls: Vec<u8>,
+    rows: usize,
+    values: Values,
+}
+
+impl Column {
+    pub fn new(name: String, kind: ColumnKind) -> Self {
+        let values = match kind {
+            ColumnKind::Long => Values::Long(Vec::new()),
+            ColumnKind::Double => Values::Double(Vec::new()),
+            ColumnKind::String | ColumnKind::Bytes => Values::Bytes(vec![0], Vec::new()),
+        };
+
+        Self {
+            name,
+            kind,
+            nulls: Vec::new(),
+            rows: 0,
+            values,
+        }
+    }
+
+    pub fn kind(&self) -> ColumnKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn push_null(&mut self, is_null: bool) {
+        if self.rows.is_multiple_of(8) {
+            self.nulls.push(0);
+        }
+
+        if is_null {
+            *self.nulls.last_mut().unwrap() |= 1 << (self.rows % 8);
+        }
+
+        self.rows += 1;
+    }
+
+    pub fn push_long(&mut self, value: Option<i64>) {
+        self.push_null(value.is_none());
+        match &mut self.values {
+            Values::Long(v) => v.push(value.unwrap_or(0)),
+            _ => panic!("Pushed a long to {:?} column '{}'", self.kind, self.name)
+        }
+    }
+
+    pub fn push_double(&mut self, value: Option<f64>) {
+        self.push_null(value.is_none());
+        match &mut self.values {
+            Values::Double(v) => v.push(value.unwrap_or(0.0)),
+            _ => panic!("Pushed a double to {:?} column '{}'", self.kind, self.name)
+        }
+    }
+
+    /// Push the value of a STRING or BYTES column. Strings are pushed as UTF-8.
+    /// Fails if the values of the column no longer fit the i32 offsets
+    pub fn push_bytes(&mut self, value: Option<&[u8]>) -> Result<(), String> {
+        let bytes = value.unwrap_or_default();
+        let end = match &self.values {
+            Values::Bytes(_, data) => length(data.len() + bytes.len())?,
+            _ => panic!("Pushed bytes to {:?} column '{}'", self.kind, self.name)
+        };
+
+        self.push_null(value.is_none());
+        if let Values::Bytes(offsets, data) = &mut self.values {
+            data.extend_from_slice(bytes);
+            offsets.push(end);
+        }
+
+        Ok(())
+    }
+}
+
+/// Encode columns which all have the same number of rows.
+/// Fails if the result is larger than a Java ByteBuffer can hold
+pub fn encode(columns: &[Column], rows: usize) -> Result<Vec<u8>, String> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&length(columns.len())?.to_le_bytes());
+    buf.extend_from_slice(&length(rows)?.to_le_bytes());
+
+    for column in columns {
+        buf.extend_from_slice(&length(column.name.len())?.to_le_bytes());
+        buf.extend_from_slice(column.name.as_bytes());
+        buf.push(column.kind as u8);
+
+        buf.extend_from_slice(&column.nulls);
+        match &column.values {
+            Values::Long(v) => v.iter().for_each(|x| buf.extend_from_slice(&x.to_le_bytes())),
+            Values::Double(v) => v.iter().for_each(|x| buf.extend_from_slice(&x.to_le_bytes())),
+            Values::Bytes(offsets, data) => {
+                offsets.iter().for_each(|x| buf.extend_from_slice(&x.to_le_bytes()));
+                buf.extend_from_slice(data);
+            }
+        }
+    }
+
+    length(buf.len())?;
+    Ok(buf)
+}
+
+/// Convert a length or count to the i32 Java uses for them
+fn length(len: usize) -> Result<i32, String> {
+    i32::try_from(len).map_err(|_| "Columnar result too large, use queryStream".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_header() {
+        let mut column = Column::new("a".to_string(), ColumnKind::Bytes);
+        column.push_bytes(Some(b"xy")).unwrap();
+        column.push_bytes(None).unwrap();
+
+        let buf = encode(&[column], 2).unwrap();
+        assert_eq!(&buf[..8], &[1, 0, 0, 0, 2, 0, 0, 0]);
+        // Name, kind, null bitmap, offsets and values
+        assert_eq!(&buf[8..], &[1, 0, 0, 0, b'a', 3, 0b10, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, b'x', b'y']);
+    }
+
+    #[test]
+    fn rejects_lengths_beyond_i32() {
+        assert_eq!(length(i32::MAX as usize), Ok(i32::MAX));
+        assert!(length(i32::MAX as usize + 1).is_err());
+    }
+}
@@ -1,12 +1,18 @@
 use std::error::Error;
+use std::fmt;
 use jni::objects::JObject;
+use jni::signature::{JavaType, Primitive};
 use jni::JNIEnv;
 use jni::sys::jobjectArray;
 use mysql::Value;
 use postgres::types::{IsNull, ToSql, Type};
 use postgres::types::private::BytesMut;
+use crate::jni::cache::cache;
+use crate::jni::postgresql_driver::types::Interval;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
 
 pub type SqlParameterArray = jobjectArray;
+pub type SqlParameterSetArray = jobjectArray;
 
 #[derive(Debug)]
 pub enum SqlParameter {
@@ -15,17 +21,82 @@ pub enum SqlParameter {
     Int(i64),
     Float(f32),
     Double(f64),
+    /// Days since 1970-01-01
+    Date(i64),
+    /// Seconds since 1970-01-01T00:00 and the nanoseconds of that second, without a time zone
+    DateTime(i64, u32),
+    /// Seconds since the Unix epoch and the nanoseconds of that second
+    Timestamp(i64, u32),
+    /// A duration in seconds and the nanoseconds of that second. The nanoseconds are always positive
+    Time(i64, u32),
 }
 
-impl Into<mysql::Value> for SqlParameter {
-    fn into(self) -> Value {
-        match self {
-            Self::Null => mysql::Value::NULL,
-            Self::Int(x) => mysql::Value::Int(x),
-            Self::Bytes(x) => mysql::Value::Bytes(x),
-            Self::Float(x) => mysql::Value::Float(x),
-            Self::Double(x) => mysql::Value::Double(x),
-        }
+/// Get the date a number of days after 1970-01-01
+fn date_from_unix_days(days: i64) -> Result<Date, String> {
+    OffsetDateTime::UNIX_EPOCH.date().checked_add(Duration::days(days))
+        .ok_or_else(|| format!("Date {} days from 1970-01-01 is out of range", days))
+}
+
+/// Get the point in time a number of seconds and nanoseconds after 1970-01-01T00:00 UTC
+fn date_time_from_unix(seconds: i64, nanos: u32) -> Result<OffsetDateTime, String> {
+    OffsetDateTime::from_unix_timestamp_nanos(seconds as i128 * 1_000_000_000 + nanos as i128)
+        .map_err(|_| format!("Timestamp {} seconds from 1970-01-01 is out of range", seconds))
+}
+
+fn duration(seconds: i64, nanos: u32) -> Result<Duration, String> {
+    Duration::seconds(seconds).checked_add(Duration::nanoseconds(nanos as i64))
+        .ok_or_else(|| format!("Duration of {} seconds is out of range", seconds))
+}
+
+/// Convert a date and time to a MySQL date. MySQL stores microseconds at most, and only the years 0 to 9999
+fn mysql_date_time(date: Date, time: Time) -> Result<Value, String> {
+    let year = match date.year() {
+        year @ 0..=9999 => year as u16,
+        year => return Err(format!("Year {} is out of range for MySQL, which supports years 0 to 9999", year))
+    };
+
+    Ok(Value::Date(year, date.month() as u8, date.day(), time.hour(), time.minute(), time.second(), time.microsecond()))
+}
+
+/// Convert a duration to a MySQL time, which is stored as a sign and the magnitude
+fn mysql_time(duration: Duration) -> Result<Value, String> {
+    let magnitude = duration.abs();
+    let days = match u32::try_from(magnitude.whole_days()) {
+        Ok(days) => days,
+        Err(_) => return Err(format!("Duration {} is out of range for MySQL", duration))
+    };
+
+    Ok(Value::Time(
+        duration.is_negative(),
+        days,
+        (magnitude.whole_hours() % 24) as u8,
+        (magnitude.whole_minutes() % 60) as u8,
+        (magnitude.whole_seconds() % 60) as u8,
+        magnitude.subsec_microseconds() as u32
+    ))
+}
+
+/// Fails for values MySQL can't store, such as dates outside of the years 0 to 9999
+impl TryFrom<SqlParameter> for mysql::Value {
+    type Error = String;
+
+    fn try_from(param: SqlParameter) -> Result<Value, String> {
+        let value = match param {
+            SqlParameter::Null => mysql::Value::NULL,
+            SqlParameter::Int(x) => mysql::Value::Int(x),
+            SqlParameter::Bytes(x) => mysql::Value::Bytes(x),
+            SqlParameter::Float(x) => mysql::Value::Float(x),
+            SqlParameter::Double(x) => mysql::Value::Double(x),
+            SqlParameter::Date(x) => mysql_date_time(date_from_unix_days(x)?, Time::MIDNIGHT)?,
+            // The connection's time zone is set to UTC when it is opened
+            SqlParameter::DateTime(seconds, nanos) | SqlParameter::Timestamp(seconds, nanos) => {
+                let date_time = date_time_from_unix(seconds, nanos)?;
+                mysql_date_time(date_time.date(), date_time.time())?
+            },
+            SqlParameter::Time(seconds, nanos) => mysql_time(duration(seconds, nanos)?)?,
+        };
+
+        Ok(value)
     }
 }
 
@@ -37,6 +108,24 @@ impl ToSql for SqlParameter {
             Self::Bytes(x) => x.to_sql(ty, out),
             Self::Float(x) => x.to_sql(ty, out),
             Self::Double(x) => x.to_sql(ty, out),
+            Self::Date(x) => date_from_unix_days(*x)?.to_sql_checked(ty, out),
+            // The binary format of timestamptz is in UTC, so a date and time without a zone is taken to be in UTC
+            Self::DateTime(seconds, nanos) | Self::Timestamp(seconds, nanos) => {
+                let date_time = date_time_from_unix(*seconds, *nanos)?;
+                match *ty {
+                    Type::TIMESTAMP => PrimitiveDateTime::new(date_time.date(), date_time.time()).to_sql_checked(ty, out),
+                    _ => date_time.to_sql_checked(ty, out)
+                }
+            },
+            Self::Time(seconds, nanos) if *ty == Type::INTERVAL => Interval::from_duration(duration(*seconds, *nanos)?)?.to_sql_checked(ty, out),
+            Self::Time(seconds, nanos) => {
+                let duration = duration(*seconds, *nanos)?;
+                if duration.is_negative() || duration >= Duration::DAY {
+                    return Err(format!("Duration {} is not a time of day", duration).into());
+                }
+
+                (Time::MIDNIGHT + duration).to_sql_checked(ty, out)
+            },
         }
     }
 
@@ -49,20 +138,47 @@ impl ToSql for SqlParameter {
     }
 }
 
-pub fn into_sql_parameter(env: &JNIEnv<'_>, param: JObject) -> Result<SqlParameter, jni::errors::Error> {
-    let param_type = env.get_field(param, "type", "Ldev/array21/jdbd/datatypes/SqlParameterType;")?.l()?;
-    let param_type_string_object = env.call_method(param_type, "toString", "()Ljava/lang/String;", &[])?.l()?;
+/// Converting a Java `SqlParameter` failed
+#[derive(Debug)]
+pub enum SqlParameterError {
+    Jni(jni::errors::Error),
+    /// The parameter type is not known to this library, e.g. because it is older than the Java library
+    UnsupportedType(String),
+}
+
+impl From<jni::errors::Error> for SqlParameterError {
+    fn from(e: jni::errors::Error) -> Self {
+        Self::Jni(e)
+    }
+}
+
+impl fmt::Display for SqlParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Jni(e) => write!(f, "{:?}", e),
+            Self::UnsupportedType(t) => write!(f, "Unsupported sql parameter type '{}'", t),
+        }
+    }
+}
+
+pub fn into_sql_parameter(env: &JNIEnv<'_>, param: JObject) -> Result<SqlParameter, SqlParameterError> {
+    let cache = cache();
+    let param_type = env.get_field_unchecked(param, cache.SqlParameter_type.get(), JavaType::Object(String::new()))?.l()?;
+    let param_type_string_object = env.call_method_unchecked(param_type, cache.Enum_name.get(), JavaType::Object(String::new()), &[])?.l()?;
     let param_type_string: String = env.get_string(param_type_string_object.into())?.into();
+    env.delete_local_ref(param_type_string_object)?;
+    env.delete_local_ref(param_type)?;
 
     let result = match param_type_string.as_str() {
         "NULL" => {
             SqlParameter::Null
         },
         "BYTES" => {
-            let bytes_object = env.get_field(param, "bytesVal", "[B")?.l()?;
+            let bytes_object = env.get_field_unchecked(param, cache.SqlParameter_bytesVal.get(), JavaType::Array(Box::new(JavaType::Primitive(Primitive::Byte))))?.l()?;
             let bytes_len = env.get_array_length(bytes_object.into_inner())?;
             let mut buf = vec![0i8; bytes_len as usize];
             env.get_byte_array_region(bytes_object.into_inner(), 0, &mut buf)?;
+            env.delete_local_ref(bytes_object)?;
 
             let buf = buf.into_iter()
                 .map(|x| x as u8)
@@ -71,19 +187,82 @@ pub fn into_sql_parameter(env: &JNIEnv<'_>, param: JObject) -> Result<SqlParamet
             SqlParameter::Bytes(buf)
         },
         "INT" => {
-            let int_object = env.get_field(param, "longVal", "J")?.j()?;
+            let int_object = env.get_field_unchecked(param, cache.SqlParameter_longVal.get(), JavaType::Primitive(Primitive::Long))?.j()?;
             SqlParameter::Int(int_object)
         },
         "FLOAT" => {
-            let float_object = env.get_field(param, "floatVal", "F")?.f()?;
+            let float_object = env.get_field_unchecked(param, cache.SqlParameter_floatVal.get(), JavaType::Primitive(Primitive::Float))?.f()?;
             SqlParameter::Float(float_object)
         },
         "DOUBLE" => {
-            let double_object = env.get_field(param, "doubleVal", "D")?.d()?;
+            let double_object = env.get_field_unchecked(param, cache.SqlParameter_doubleVal.get(), JavaType::Primitive(Primitive::Double))?.d()?;
             SqlParameter::Double(double_object)
         },
-        _ => panic!("Invalid sql parameter type {}", &param_type_string)
+        "DATE" => {
+            let days = env.get_field_unchecked(param, cache.SqlParameter_longVal.get(), JavaType::Primitive(Primitive::Long))?.j()?;
+            SqlParameter::Date(days)
+        },
+        "DATETIME" | "TIMESTAMP" | "TIME" => {
+            let seconds = env.get_field_unchecked(param, cache.SqlParameter_longVal.get(), JavaType::Primitive(Primitive::Long))?.j()?;
+            let nanos = env.get_field_unchecked(param, cache.SqlParameter_nanoVal.get(), JavaType::Primitive(Primitive::Int))?.i()? as u32;
+            match param_type_string.as_str() {
+                "DATETIME" => SqlParameter::DateTime(seconds, nanos),
+                "TIMESTAMP" => SqlParameter::Timestamp(seconds, nanos),
+                _ => SqlParameter::Time(seconds, nanos)
+            }
+        },
+        _ => return Err(SqlParameterError::UnsupportedType(param_type_string))
     };
 
     Ok(result)
+}
+
+/// Convert a Java `SqlParameter[]` to Rust. The error describes the step which failed
+pub fn read_sql_parameters(env: &JNIEnv<'_>, params: SqlParameterArray) -> Result<Vec<SqlParameter>, String> {
+    let params_len = env.get_array_length(params)
+        .map_err(|e| format!("Failed to get params array length: {:?}", e))?;
+
+    (0..params_len)
+        .map(|x| {
+            let param = env.get_object_array_element(params, x)
+                .map_err(|e| format!("Failed to fetch element of sql params array: {:?}", e))?;
+            into_sql_parameter(env, param)
+                .map_err(|e| format!("Failed to convert sql params element to Rust type: {}", e))
+        })
+        .collect()
+}
+
+/// Convert a Java `SqlParameter[][]` to one Vec of parameters per set.
+/// Local references are deleted as soon as a parameter or set has been converted, so they don't pile up over large batches
+pub fn into_sql_parameter_sets(env: &JNIEnv<'_>, sets: SqlParameterSetArray) -> Result<Vec<Vec<SqlParameter>>, SqlParameterError> {
+    let sets_len = env.get_array_length(sets)?;
+    (0..sets_len)
+        .map(|x| {
+            let set = env.get_object_array_element(sets, x)?;
+            let set_len = env.get_array_length(set.into_inner())?;
+            let params = (0..set_len)
+                .map(|y| {
+                    let param = env.get_object_array_element(set.into_inner(), y)?;
+                    let result = into_sql_parameter(env, param);
+                    env.delete_local_ref(param)?;
+                    result
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            env.delete_local_ref(set)?;
+            Ok(params)
+        })
+        .collect()
+}
+
+/// Check if a savepoint name is a plain SQL identifier.
+/// Savepoint names can't be bound as parameters, so anything else is refused to prevent SQL injection
+pub fn is_valid_savepoint_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {},
+        _ => return false
+    }
+
+    name.len() <= 63 && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
\ No newline at end of file
@@ -0,0 +1,218 @@
+use std::io;
+use std::error::Error;
+use jni::JNIEnv;
+use jni::objects::{JObject, JValue, JThrowable};
+use crate::jni::util::Java;
+
+/// Checked exception for everything that goes wrong while running a statement
+pub const SQL_EXCEPTION: &str = "dev/array21/jdbd/exceptions/SqlException";
+/// Unchecked exception for errors in the native library itself
+pub const DRIVER_EXCEPTION: &str = "dev/array21/jdbd/exceptions/DriverException";
+/// Unchecked exception for errors while initializing a driver
+pub const DRIVER_LOAD_EXCEPTION: &str = "dev/array21/jdbd/exceptions/DriverLoadException";
+/// Unchecked exception for handles which are not, or no longer, valid
+pub const INVALID_HANDLE_EXCEPTION: &str = "dev/array21/jdbd/exceptions/InvalidHandleException";
+
+/// Throw a new exception of the provided class.
+/// If an exception is already pending, e.g. because a call into the JVM failed, it is set as the cause of the new exception
+pub fn throw(env: JNIEnv, class: &str, msg: &str) {
+    let cause = match env.exception_occurred() {
+        Ok(c) if !c.is_null() => {
+            let _ = env.exception_clear();
+            Some(c)
+        },
+        _ => None
+    };
+
+    let result = match cause {
+        Some(cause) => env.new_string(msg)
+            .and_then(|msg| env.new_object(class, "(Ljava/lang/String;Ljava/lang/Throwable;)V", &[JValue::Object(msg.into()), JValue::Object(cause.into())]))
+            .and_then(|e| env.throw(JThrowable::from(e))),
+        None => env.throw_new(class, msg)
+    };
+
+    // Only happens when the JVM is out of memory or the class is missing, in which case the JVM already has an exception pending
+    if let Err(e) = result {
+        eprintln!("Failed to throw {}: {}: {:?}", class, msg, e);
+    }
+}
+
+/// The kind of error, decides which subclass of dev.array21.jdbd.exceptions.SqlException is thrown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlErrorKind {
+    Other,
+    UniqueViolation,
+    ForeignKeyViolation,
+    Deadlock,
+    ConnectionLost,
+}
+
+impl SqlErrorKind {
+    fn class(&self) -> &'static str {
+        match self {
+            Self::Other => SQL_EXCEPTION,
+            Self::UniqueViolation => "dev/array21/jdbd/exceptions/UniqueViolationException",
+            Self::ForeignKeyViolation => "dev/array21/jdbd/exceptions/ForeignKeyViolationException",
+            Self::Deadlock => "dev/array21/jdbd/exceptions/DeadlockException",
+            Self::ConnectionLost => "dev/array21/jdbd/exceptions/ConnectionLostException",
+        }
+    }
+
+    /// Get the kind of error from a SQLSTATE
+    fn from_sql_state(sql_state: &str) -> Self {
+        match sql_state {
+            "23505" => Self::UniqueViolation,
+            "23503" => Self::ForeignKeyViolation,
+            "40P01" => Self::Deadlock,
+            // Class 08 is 'Connection Exception', 57P01 to 57P03 are the server shutting down
+            s if s.starts_with("08") => Self::ConnectionLost,
+            "57P01" | "57P02" | "57P03" => Self::ConnectionLost,
+            _ => Self::Other
+        }
+    }
+}
+
+/// An error reported by the database, or by the driver while talking to it
+#[derive(Debug)]
+pub struct SqlError {
+    pub kind: SqlErrorKind,
+    pub message: String,
+    pub sql_state: Option<String>,
+    pub vendor_code: Option<i32>,
+    pub detail: Option<String>,
+    pub hint: Option<String>,
+    pub constraint: Option<String>,
+    pub table: Option<String>,
+    pub column: Option<String>,
+}
+
+impl SqlError {
+    fn new(kind: SqlErrorKind, message: String) -> Self {
+        Self {
+            kind,
+            message,
+            sql_state: None,
+            vendor_code: None,
+            detail: None,
+            hint: None,
+            constraint: None,
+            table: None,
+            column: None,
+        }
+    }
+
+    /// Create an SqlError for a column whose value can't be converted to a Java type
+    pub fn column(column: &str, message: String) -> Self {
+        Self {
+            column: Some(column.to_string()),
+            ..Self::new(SqlErrorKind::Other, message)
+        }
+    }
+
+    /// Create an SqlError from a PostgreSQL error. `context` is prefixed to the message
+    pub fn from_postgres(context: &str, e: &postgres::Error) -> Self {
+        let message = format!("{}: {}", context, e);
+
+        let db_error = match e.as_db_error() {
+            Some(d) => d,
+            None => {
+                let lost = e.is_closed() || e.source().map(|s| s.is::<io::Error>()).unwrap_or(false);
+                let kind = if lost { SqlErrorKind::ConnectionLost } else { SqlErrorKind::Other };
+                return Self::new(kind, message);
+            }
+        };
+
+        let sql_state = db_error.code().code();
+        Self {
+            kind: SqlErrorKind::from_sql_state(sql_state),
+            message,
+            sql_state: Some(sql_state.to_string()),
+            vendor_code: None,
+            detail: db_error.detail().map(String::from),
+            hint: db_error.hint().map(String::from),
+            constraint: db_error.constraint().map(String::from),
+            table: db_error.table().map(String::from),
+            column: db_error.column().map(String::from),
+        }
+    }
+
+    /// Create an SqlError from a MySQL error. `context` is prefixed to the message
+    pub fn from_mysql(context: &str, e: &mysql::Error) -> Self {
+        let message = format!("{}: {}", context, e);
+
+        let mysql_error = match e {
+            mysql::Error::MySqlError(m) => m,
+            mysql::Error::IoError(_) | mysql::Error::CodecError(_) => return Self::new(SqlErrorKind::ConnectionLost, message),
+            mysql::Error::DriverError(d) => {
+                let kind = match d {
+                    mysql::DriverError::ConnectTimeout
+                    | mysql::DriverError::CouldNotConnect(_)
+                    | mysql::DriverError::PacketOutOfSync => SqlErrorKind::ConnectionLost,
+                    _ => SqlErrorKind::Other
+                };
+                return Self::new(kind, message);
+            },
+            _ => return Self::new(SqlErrorKind::Other, message)
+        };
+
+        // MySQL reports SQLSTATE 23000 for all integrity constraint violations, so the error code is used instead
+        let kind = match mysql_error.code {
+            // ER_DUP_ENTRY, ER_DUP_ENTRY_WITH_KEY_NAME
+            1062 | 1586 => SqlErrorKind::UniqueViolation,
+            // ER_NO_REFERENCED_ROW, ER_ROW_IS_REFERENCED, ER_ROW_IS_REFERENCED_2, ER_NO_REFERENCED_ROW_2
+            1216 | 1217 | 1451 | 1452 => SqlErrorKind::ForeignKeyViolation,
+            // ER_LOCK_DEADLOCK
+            1213 => SqlErrorKind::Deadlock,
+            _ => SqlErrorKind::from_sql_state(&mysql_error.state)
+        };
+
+        Self {
+            sql_state: Some(mysql_error.state.clone()),
+            vendor_code: Some(mysql_error.code as i32),
+            ..Self::new(kind, message)
+        }
+    }
+
+    /// Create the Java exception for this error
+    fn to_throwable<'a>(&self, env: JNIEnv<'a>) -> Result<JThrowable<'a>, String> {
+        let string_or_null = |s: &Option<String>| -> Result<JObject<'a>, String> {
+            match s {
+                Some(s) => Ok(Java::new_String(env, s.clone())?.into()),
+                None => Ok(JObject::null())
+            }
+        };
+
+        let vendor_code = match self.vendor_code {
+            Some(c) => Java::new_Integer(env, c)?,
+            None => JObject::null()
+        };
+
+        let args = [
+            JValue::Object(Java::new_String(env, self.message.clone())?.into()),
+            JValue::Object(string_or_null(&self.sql_state)?),
+            JValue::Object(vendor_code),
+            JValue::Object(string_or_null(&self.detail)?),
+            JValue::Object(string_or_null(&self.hint)?),
+            JValue::Object(string_or_null(&self.constraint)?),
+            JValue::Object(string_or_null(&self.table)?),
+            JValue::Object(string_or_null(&self.column)?),
+        ];
+
+        let sig = "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/Integer;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)V";
+        match env.new_object(self.kind.class(), sig, &args) {
+            Ok(t) => Ok(JThrowable::from(t)),
+            Err(e) => Err(format!("Failed to create new {}: {:?}", self.kind.class(), e))
+        }
+    }
+
+    /// Throw this error as the matching subclass of dev.array21.jdbd.exceptions.SqlException.
+    /// If the exception can not be created, a plain SqlException is thrown instead
+    pub fn throw(&self, env: JNIEnv) {
+        let result = self.to_throwable(env)
+            .and_then(|t| env.throw(t).map_err(|e| format!("Failed to throw exception: {:?}", e)));
+
+        if result.is_err() {
+            throw(env, SQL_EXCEPTION, &self.message);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Native objects, like drivers, live in a registry. Java only holds on to a handle to them.
+//!
+//! A handle is made up of the index of the object's slot in the registry and the generation of that slot.
+//! The generation is bumped every time an object is removed from a slot, so a handle which has already
+//! been removed, or which was never handed out at all, can be told apart from a valid one.
+//! Objects are also checked against the type the caller expects, so a handle of one driver can't be used with another driver.
+//!
+//! Getting an object gives out a reference counted pointer, so an object removed while a call is still using it
+//! stays alive until that call is done.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use jni::sys::jlong;
+
+type Object = Arc<dyn Any + Send + Sync>;
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry::new());
+
+struct Slot {
+    generation: u32,
+    object: Option<Object>,
+}
+
+struct Registry {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+}
+
+/// The reason a handle could not be resolved
+#[derive(Debug)]
+pub enum HandleError {
+    /// The handle was never handed out by the registry
+    Invalid,
+    /// The object behind the handle has already been removed
+    Freed,
+    /// The object behind the handle is of a different type than expected
+    WrongType,
+}
+
+impl fmt::Display for HandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => write!(f, "Invalid handle"),
+            Self::Freed => write!(f, "Handle has already been freed"),
+            Self::WrongType => write!(f, "Handle refers to an object of a different type"),
+        }
+    }
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    fn slot(&mut self, handle: jlong) -> Result<&mut Slot, HandleError> {
+        let handle = handle as u64;
+        let index = (handle & u32::MAX as u64) as usize;
+        let generation = (handle >> 32) as u32;
+
+        let slot = match self.slots.get_mut(index) {
+            Some(s) => s,
+            None => return Err(HandleError::Invalid)
+        };
+
+        if slot.generation != generation {
+            // Generations only ever go up, so an older generation means the object was removed
+            return if generation != 0 && generation < slot.generation {
+                Err(HandleError::Freed)
+            } else {
+                Err(HandleError::Invalid)
+            };
+        }
+
+        Ok(slot)
+    }
+}
+
+fn registry() -> std::sync::MutexGuard<'static, Registry> {
+    // The registry is never left in an inconsistent state, so a poisoned lock is safe to use
+    REGISTRY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Move an object into the registry and return the handle to give to Java.
+/// A handle is never 0
+pub fn insert_handle<T: Any + Send + Sync>(object: T) -> jlong {
+    let mut registry = registry();
+    let object: Object = Arc::new(object);
+
+    let index = match registry.free.pop() {
+        Some(index) => {
+            registry.slots[index as usize].object = Some(object);
+            index
+        },
+        None => {
+            // Generations start at 1, so that 0 is never a valid handle
+            registry.slots.push(Slot { generation: 1, object: Some(object) });
+            (registry.slots.len() - 1) as u32
+        }
+    };
+
+    let generation = registry.slots[index as usize].generation;
+    (((generation as u64) << 32) | index as u64) as jlong
+}
+
+/// Get the object behind a handle
+pub fn get_handle<T: Any + Send + Sync>(handle: jlong) -> Result<Arc<T>, HandleError> {
+    let mut registry = registry();
+    let object = match &registry.slot(handle)?.object {
+        Some(o) => o.clone(),
+        None => return Err(HandleError::Freed)
+    };
+
+    object.downcast::<T>().map_err(|_| HandleError::WrongType)
+}
+
+/// Remove the object behind a handle from the registry. The object is dropped once no call is using it anymore.
+/// After this, the handle is no longer valid
+pub fn remove_handle<T: Any + Send + Sync>(handle: jlong) -> Result<(), HandleError> {
+    let object = {
+        let mut registry = registry();
+        let slot = registry.slot(handle)?;
+        match &slot.object {
+            Some(o) if o.is::<T>() => {},
+            Some(_) => return Err(HandleError::WrongType),
+            None => return Err(HandleError::Freed)
+        }
+
+        let object = slot.object.take();
+        slot.generation = slot.generation.wrapping_add(1).max(1);
+        let index = (handle as u64 & u32::MAX as u64) as u32;
+        registry.free.push(index);
+        object
+    };
+
+    // Drop the object outside of the lock, dropping a driver closes its connections which can take a while
+    drop(object);
+    Ok(())
+}
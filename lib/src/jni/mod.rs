@@ -2,3 +2,8 @@ mod mysql_driver;
 mod util;
 mod postgresql_driver;
 mod common;
+mod handle;
+mod error;
+mod pinned;
+mod cache;
+mod columnar;
@@ -0,0 +1,67 @@
+use std::sync::Mutex;
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use mysql::{AccessMode, IsolationLevel, TxOpts};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::{get_boolean_field, get_enum_field, catch_panic};
+use crate::jni::pinned::Pinned;
+use crate::unwrap_zero;
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     beginTransactionNative
+ * - Signature:  `(JLdev/array21/jdbd/datatypes/TransactionOptions;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_beginTransactionNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, options: JObject<'_>) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let isolation_level = unwrap_zero!(get_enum_field(env, options, "isolationLevel", "Ldev/array21/jdbd/datatypes/IsolationLevel;"));
+        let read_only = unwrap_zero!(get_boolean_field(env, options, "readOnly"));
+
+        let isolation_level = match isolation_level.as_deref() {
+            Some("READ_UNCOMMITTED") => Some(IsolationLevel::ReadUncommitted),
+            Some("READ_COMMITTED") => Some(IsolationLevel::ReadCommitted),
+            Some("REPEATABLE_READ") => Some(IsolationLevel::RepeatableRead),
+            Some("SERIALIZABLE") => Some(IsolationLevel::Serializable),
+            Some(level) => {
+                throw(env, SQL_EXCEPTION, &format!("Invalid isolation level '{}'", level));
+                return 0;
+            },
+            None => None
+        };
+
+        // MySQL has no equivalent of PostgreSQL's DEFERRABLE, so that option is ignored
+        let tx_opts = TxOpts::default()
+            .set_isolation_level(isolation_level)
+            .set_access_mode(if read_only { Some(AccessMode::ReadOnly) } else { None });
+
+        let conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return 0;
+            }
+        };
+
+        let transaction = match Pinned::begin(conn, |conn| conn.start_transaction(tx_opts)) {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_mysql("Failed to begin transaction", &e).throw(env);
+                return 0;
+            }
+        };
+
+        insert_handle(Mutex::new(transaction))
+    })
+}
@@ -1,74 +1,91 @@
 use jni::JNIEnv;
 use jni::objects::{JObject, JString};
-use jni::sys::{jlong, jint};
-use mysql::{Pool, Params};
-use crate::jni::util::set_error;
+use jni::sys::{jlong, jobject};
+use mysql::{Params};
+use crate::jni::util::{catch_panic, Java};
 use mysql::prelude::Queryable;
-use crate::jni::common::SqlParameterArray;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
 
 /**
  * - Class:      MysqlDriver
  * - Method:     executeNative
- * - Signature:  `(JLjava/lang/String;)I`
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)Ldev/array21/jdbd/datatypes/ExecuteResult;`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_executeNative(env: JNIEnv, obj: JObject<'_>, pool_ptr: jlong, stmt: JString, params: SqlParameterArray) -> jint {
-    let pool_ptr = pool_ptr as *mut Pool;
-    let mut conn = match unsafe { &*pool_ptr }.get_conn() {
-        Ok(c) => c,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to create MySQL connection: {:?}", e));
-            return -1;
-        }
-    };
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_executeNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, std::ptr::null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return std::ptr::null_mut();
+            }
+        };
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return std::ptr::null_mut();
+            }
+        };
+
+        execute(env, &mut *conn, stmt, params)
+    })
+}
 
+/// Execute a statement on a MySQL connection or transaction
+pub fn execute<Q: Queryable>(env: JNIEnv, conn: &mut Q, stmt: JString, params: SqlParameterArray) -> jobject {
     let stmt = match env.get_string(stmt) {
         Ok(s) => String::from(s),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert stmt from JString to String: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return std::ptr::null_mut();
         }
     };
 
-    let sql_params_len = match env.get_array_length(params) {
+    let sql_params_rust = match read_sql_parameters(&env, params) {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to get params array length: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &e);
+            return std::ptr::null_mut();
         }
     };
 
-    let sql_params_java = match (0..sql_params_len).into_iter()
-        .map(|x| env.get_object_array_element(params, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
+    let sql_params_mysql = match sql_params_rust.into_iter()
+        .map(mysql::Value::try_from)
+        .collect::<Result<Vec<_>, String>>() {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to fetch element of sql params array: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &e);
+            return std::ptr::null_mut();
         }
     };
 
-    let sql_params_rust = match sql_params_java.into_iter()
-        .map(|x| crate::jni::common::into_sql_parameter(&env, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
+    let result = match conn.exec_iter(&stmt, Params::Positional(sql_params_mysql)) {
+        Ok(r) => r,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert sql params element to Rust type: {:?}", e));
-            return -1;
+            SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
+            return std::ptr::null_mut();
         }
     };
 
-    let sql_params_mysql = sql_params_rust.into_iter()
-        .map(|x| x.into())
-        .collect::<Vec<mysql::Value>>();
+    let affected_rows = result.affected_rows();
+    let last_insert_id = result.last_insert_id();
+    let warnings = result.warnings();
+    let info = result.info_str();
+    let info = if info.is_empty() { None } else { Some(info.into_owned()) };
+
+    // Dropping the result consumes any remaining result sets
+    drop(result);
 
-    match conn.exec_drop(&stmt, Params::Positional(sql_params_mysql)) {
-        Ok(_) => {},
+    match Java::new_ExecuteResult(env, affected_rows, last_insert_id, warnings, info) {
+        Ok(r) => r.into_inner(),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to execute stmt: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &e);
+            std::ptr::null_mut()
         }
     }
-
-    0
-}
\ No newline at end of file
+}
\ No newline at end of file
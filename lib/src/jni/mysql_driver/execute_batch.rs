@@ -0,0 +1,116 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jlongArray};
+use mysql::{Params, TxOpts};
+use mysql::prelude::Queryable;
+use std::ptr::null_mut;
+use crate::jni::util::{Java, catch_panic};
+use crate::jni::common::{SqlParameterSetArray, into_sql_parameter_sets};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     executeBatchNative
+ * - Signature:  `(JLjava/lang/String;[[Ldev/array21/jdbd/datatypes/SqlParameter;)[J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_executeBatchNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, param_sets: SqlParameterSetArray) -> jlongArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        let mut transaction = match conn.start_transaction(TxOpts::default()) {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_mysql("Failed to begin transaction", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        // The transaction is rolled back when it is dropped without being committed
+        let counts = execute_batch(env, &mut transaction, stmt, param_sets);
+        if counts.is_null() {
+            return null_mut();
+        }
+
+        if let Err(e) = transaction.commit() {
+            SqlError::from_mysql("Failed to commit batch", &e).throw(env);
+            return null_mut();
+        }
+
+        counts
+    })
+}
+
+/// Prepare a statement once and execute it for every parameter set on a MySQL connection or transaction.
+/// Returns the affected row count per parameter set, or null if an exception was thrown
+pub fn execute_batch<Q: Queryable>(env: JNIEnv, conn: &mut Q, stmt: JString, param_sets: SqlParameterSetArray) -> jlongArray {
+    let stmt = match env.get_string(stmt) {
+        Ok(s) => String::from(s),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    let param_sets = match into_sql_parameter_sets(&env, param_sets) {
+        Ok(x) => x,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert sql param sets to Rust types: {}", e));
+            return null_mut();
+        }
+    };
+
+    // Convert every set before executing any, so a set MySQL can't store does not leave the batch half executed
+    let param_sets = match param_sets.into_iter()
+        .map(|params| params.into_iter().map(mysql::Value::try_from).collect::<Result<Vec<_>, String>>())
+        .collect::<Result<Vec<_>, String>>() {
+        Ok(x) => x,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            return null_mut();
+        }
+    };
+
+    let prepared = match conn.prep(&stmt) {
+        Ok(p) => p,
+        Err(e) => {
+            SqlError::from_mysql("Failed to prepare stmt", &e).throw(env);
+            return null_mut();
+        }
+    };
+
+    // exec_batch does not report affected rows, so every set is executed individually on the prepared statement
+    let mut counts = Vec::with_capacity(param_sets.len());
+    for params in param_sets {
+        match conn.exec_iter(&prepared, Params::Positional(params)) {
+            Ok(result) => counts.push(result.affected_rows() as i64),
+            Err(e) => {
+                SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
+                return null_mut();
+            }
+        }
+    }
+
+    match Java::new_long_array(env, counts) {
+        Ok(a) => a,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            null_mut()
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jobject, jobjectArray};
+use mysql::Params;
+use mysql::prelude::Queryable;
+use std::ptr::null_mut;
+use crate::jni::util::{Java, catch_panic, in_local_frame, to_sql_row_array};
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::unwrap_nullptr;
+
+/// Name of the column holding the generated keys, as used by MySQL Connector/J
+const GENERATED_KEY_COLUMN: &str = "GENERATED_KEY";
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     executeGeneratedKeysNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)[Ldev/array21/jdbd/datatypes/SqlRow;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_executeGeneratedKeysNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        execute_generated_keys(env, &mut *conn, stmt, params)
+    })
+}
+
+/// Execute a statement on a MySQL connection or transaction, returning the AUTO_INCREMENT values it generated.
+///
+/// MySQL only reports the first generated value. For multi-row inserts the other values are derived from it,
+/// the number of affected rows and `auto_increment_increment`, which holds as long as the rows are allocated consecutively,
+/// as InnoDB does for simple inserts. This does not hold for `INSERT ... ON DUPLICATE KEY UPDATE` or `INSERT IGNORE`
+pub fn execute_generated_keys<Q: Queryable>(env: JNIEnv, conn: &mut Q, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+    let stmt = match env.get_string(stmt) {
+        Ok(s) => String::from(s),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    let sql_params_rust = match read_sql_parameters(&env, params) {
+        Ok(x) => x,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            return null_mut();
+        }
+    };
+
+    let sql_params_mysql = match sql_params_rust.into_iter()
+        .map(mysql::Value::try_from)
+        .collect::<Result<Vec<_>, String>>() {
+        Ok(x) => x,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            return null_mut();
+        }
+    };
+
+    let result = match conn.exec_iter(&stmt, Params::Positional(sql_params_mysql)) {
+        Ok(r) => r,
+        Err(e) => {
+            SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
+            return null_mut();
+        }
+    };
+
+    let affected_rows = result.affected_rows();
+    let last_insert_id = result.last_insert_id();
+    drop(result);
+
+    let keys = match last_insert_id {
+        Some(first) if affected_rows > 1 => {
+            let increment = match conn.query_first::<u64, _>("SELECT @@auto_increment_increment") {
+                Ok(i) => i.unwrap_or(1),
+                Err(e) => {
+                    SqlError::from_mysql("Failed to get auto_increment_increment", &e).throw(env);
+                    return null_mut();
+                }
+            };
+
+            (0..affected_rows).map(|x| first + x * increment).collect::<Vec<_>>()
+        },
+        Some(first) => vec![first],
+        None => Vec::new()
+    };
+
+    to_sql_row_array(env, &keys, to_sql_row)
+}
+
+/// Convert a generated key to a dev.array21.jdbd.datatypes.SqlRow. Returns a nullptr if an exception was thrown
+fn to_sql_row(env: JNIEnv, key: &u64) -> jobject {
+    in_local_frame(env, 8, || {
+        let class = unwrap_nullptr!(env, Java::Long(env));
+        let long = unwrap_nullptr!(env, Java::new_Long(env, *key as i64));
+        let sqlrow_java = unwrap_nullptr!(env, Java::new_SqlRow(env, vec![GENERATED_KEY_COLUMN.to_string()], vec![long], vec![class]));
+        sqlrow_java.into_inner()
+    })
+}
@@ -1,9 +1,15 @@
 use jni::sys::jlong;
 use jni::objects::JObject;
 use jni::JNIEnv;
-use mysql::{Pool, OptsBuilder};
-use crate::jni::util::{set_error, get_string_field};
+use std::time::Duration;
+use mysql::{Opts, OptsBuilder};
+use crate::jni::error::{throw, DRIVER_LOAD_EXCEPTION};
+use crate::jni::util::{get_string_field, get_string_array_field, get_boolean_field, get_integer_field, get_long_field, catch_panic};
+use crate::jni::mysql_driver::tls::TlsOptions;
+use crate::jni::mysql_driver::local_infile::whitelist_handler;
+use crate::jni::mysql_driver::pool::{MysqlConnectionManager, MysqlPool, DEFAULT_MIN_POOL_SIZE, DEFAULT_MAX_POOL_SIZE};
 use crate::unwrap_zero;
+use crate::jni::handle::insert_handle;
 
 /**
  * - Class:      MysqlDriver
@@ -12,26 +18,113 @@ use crate::unwrap_zero;
  */
 #[no_mangle]
 pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_initializeNative(env: JNIEnv, obj: JObject<'_>) -> jlong {
-    let host = unwrap_zero!(get_string_field(env, obj, "host"));
-    let database = unwrap_zero!(get_string_field(env, obj, "database"));
-    let username = unwrap_zero!(get_string_field(env, obj, "username"));
-    let password = unwrap_zero!(get_string_field(env, obj, "password"));
-
-    let cfg = OptsBuilder::new()
-        .ip_or_hostname(host)
-        .user(username)
-        .pass(password)
-        .db_name(database);
-
-    let pool = match Pool::new(cfg) {
-        Ok(p) => p,
-        Err(e) => {
-            let error = format!("Failed to create MySQL Connection Pool: {:?}", e);
-            set_error(env, obj, &error);
-            return 0;
-        }
-    };
-
-    let rawptr = Box::into_raw(Box::new(pool));
-    rawptr as i64
-}
\ No newline at end of file
+    catch_panic(env, DRIVER_LOAD_EXCEPTION, 0, || {
+        let host = unwrap_zero!(get_string_field(env, obj, "host"));
+        let database = unwrap_zero!(get_string_field(env, obj, "database"));
+        let username = unwrap_zero!(get_string_field(env, obj, "username"));
+        let password = unwrap_zero!(get_string_field(env, obj, "password"));
+        let port = unwrap_zero!(get_integer_field(env, obj, "port"));
+        let socket = unwrap_zero!(get_string_field(env, obj, "socket"));
+        let url = unwrap_zero!(get_string_field(env, obj, "url"));
+
+        let tls_options = TlsOptions {
+            enabled: unwrap_zero!(get_boolean_field(env, obj, "sslEnabled")),
+            ca_file: unwrap_zero!(get_string_field(env, obj, "sslCaFile")),
+            client_identity: unwrap_zero!(get_string_field(env, obj, "sslClientIdentity")),
+            client_identity_password: unwrap_zero!(get_string_field(env, obj, "sslClientIdentityPassword")),
+            skip_domain_validation: unwrap_zero!(get_boolean_field(env, obj, "sslSkipDomainValidation")),
+        };
+
+        let local_infile_paths = unwrap_zero!(get_string_array_field(env, obj, "localInfilePaths"));
+
+        let min_pool_size = unwrap_zero!(get_integer_field(env, obj, "minPoolSize"));
+        let max_pool_size = unwrap_zero!(get_integer_field(env, obj, "maxPoolSize"));
+        let idle_timeout = unwrap_zero!(get_long_field(env, obj, "idleTimeoutMillis"));
+        let max_lifetime = unwrap_zero!(get_long_field(env, obj, "maxLifetimeMillis"));
+        let acquire_timeout = unwrap_zero!(get_long_field(env, obj, "acquireTimeoutMillis"));
+        let reset_connections = unwrap_zero!(get_boolean_field(env, obj, "resetConnections"));
+
+        let mut cfg = match url {
+            Some(url) => match Opts::from_url(&url) {
+                Ok(opts) => OptsBuilder::from_opts(opts),
+                Err(e) => {
+                    throw(env, DRIVER_LOAD_EXCEPTION, &format!("Failed to parse MySQL connection URL: {:?}", e));
+                    return 0;
+                }
+            },
+            None => OptsBuilder::new()
+        };
+
+        // Only override what was explicitly set, so we don't clear values from the URL
+        if host.is_some() {
+            cfg = cfg.ip_or_hostname(host);
+        }
+
+        if let Some(port) = port {
+            cfg = cfg.tcp_port(port as u16);
+        }
+
+        if socket.is_some() {
+            cfg = cfg.socket(socket);
+        }
+
+        if username.is_some() {
+            cfg = cfg.user(username);
+        }
+
+        if password.is_some() {
+            cfg = cfg.pass(password);
+        }
+
+        if database.is_some() {
+            cfg = cfg.db_name(database);
+        }
+
+        if let Some(ssl_opts) = tls_options.build() {
+            cfg = cfg.ssl_opts(ssl_opts);
+        }
+
+        // LOAD DATA LOCAL INFILE is opt-in. Without a handler, the server is sent an empty file
+        if let Some(paths) = local_infile_paths {
+            cfg = cfg.local_infile_handler(Some(whitelist_handler(paths)));
+        }
+
+        // TIMESTAMP values are converted from and to the session time zone. Using UTC makes them map one to one on java.time.Instant.
+        // Setting the init commands replaces them, so keep those from the connection URL
+        let mut init = Opts::from(cfg.clone()).get_init();
+        init.push("SET time_zone = '+00:00'".to_string());
+        cfg = cfg.init(init);
+
+        // Without an explicit minimum, don't keep more idle connections around than the maximum allows
+        let max_pool_size = max_pool_size.map(|x| x as u32).unwrap_or(DEFAULT_MAX_POOL_SIZE);
+        let min_pool_size = min_pool_size.map(|x| x as u32).unwrap_or_else(|| DEFAULT_MIN_POOL_SIZE.min(max_pool_size));
+
+        let mut builder = MysqlPool::builder()
+            .min_idle(Some(min_pool_size))
+            .max_size(max_pool_size);
+
+        if let Some(idle_timeout) = idle_timeout {
+            builder = builder.idle_timeout(Some(Duration::from_millis(idle_timeout as u64)));
+        }
+
+        if let Some(max_lifetime) = max_lifetime {
+            builder = builder.max_lifetime(Some(Duration::from_millis(max_lifetime as u64)));
+        }
+
+        if let Some(acquire_timeout) = acquire_timeout {
+            builder = builder.connection_timeout(Duration::from_millis(acquire_timeout as u64));
+        }
+
+        let manager = MysqlConnectionManager::new(Opts::from(cfg), reset_connections);
+        let pool = match builder.build(manager) {
+            Ok(p) => p,
+            Err(e) => {
+                let error = format!("Failed to create MySQL Connection Pool: {:?}", e);
+                throw(env, DRIVER_LOAD_EXCEPTION, &error);
+                return 0;
+            }
+        };
+
+        insert_handle(pool)
+    })
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jobject};
+use mysql::TxOpts;
+use std::ptr::null_mut;
+use crate::jni::util::{Java, catch_panic};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::mysql_driver::local_infile::{SetLocalInfileHandler, stream_handler};
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     loadDataNative
+ * - Signature:  `(JLjava/lang/String;Ljava/io/InputStream;)Ldev/array21/jdbd/datatypes/ExecuteResult;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_loadDataNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, input: JObject) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        let mut transaction = match conn.start_transaction(TxOpts::default()) {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_mysql("Failed to begin transaction", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        // The transaction is rolled back when it is dropped without being committed,
+        // which undoes the rows loaded before the InputStream failed
+        let result = load_data(env, &mut transaction, stmt, input);
+        if result.is_null() {
+            return null_mut();
+        }
+
+        if let Err(e) = transaction.commit() {
+            SqlError::from_mysql("Failed to commit loaded data", &e).throw(env);
+            return null_mut();
+        }
+
+        result
+    })
+}
+
+/// Run a `LOAD DATA LOCAL INFILE` statement on a MySQL connection or transaction, serving the file from a Java InputStream.
+/// If the InputStream throws, its exception is propagated to the caller. The rows sent before that have been loaded,
+/// so the caller must roll them back
+pub fn load_data<Q: SetLocalInfileHandler>(env: JNIEnv, conn: &mut Q, stmt: JString, input: JObject) -> jobject {
+    let stmt = match env.get_string(stmt) {
+        Ok(s) => String::from(s),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    let (handler, stream_error) = match stream_handler(env, input) {
+        Ok(h) => h,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to create local infile handler: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    conn.set_local_infile_handler(Some(handler));
+    let result = conn.query_iter(&stmt).map(|result| {
+        let info = result.info_str();
+        let info = if info.is_empty() { None } else { Some(info.into_owned()) };
+        (result.affected_rows(), result.last_insert_id(), result.warnings(), info)
+    });
+    // Restore the handler configured on the pool, so the stream is not used by later statements
+    conn.set_local_infile_handler(None);
+
+    if let Some(e) = stream_error.take() {
+        // The IOException thrown by the InputStream is propagated to the caller
+        if !env.exception_check().unwrap_or(false) {
+            throw(env, SQL_EXCEPTION, &format!("Failed to load data: {}", e));
+        }
+        return null_mut();
+    }
+
+    let (affected_rows, last_insert_id, warnings, info) = match result {
+        Ok(r) => r,
+        Err(e) => {
+            SqlError::from_mysql("Failed to load data", &e).throw(env);
+            return null_mut();
+        }
+    };
+
+    match Java::new_ExecuteResult(env, affected_rows, last_insert_id, warnings, info) {
+        Ok(r) => r.into_inner(),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            null_mut()
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use jni::JNIEnv;
+use jni::objects::{GlobalRef, JObject, JValue};
+use jni::sys::jbyteArray;
+use mysql::LocalInfileHandler;
+use mysql::prelude::Queryable;
+use crate::jni::mysql_driver::pool::MysqlConnection;
+
+/// Number of bytes read from the Java InputStream at a time
+const CHUNK_SIZE: i32 = 64 * 1024;
+
+/// Connections and transactions on which the local infile handler can be overridden
+pub trait SetLocalInfileHandler: Queryable {
+    /// Override the local infile handler. `None` restores the handler configured on the connection pool
+    fn set_local_infile_handler(&mut self, handler: Option<LocalInfileHandler>);
+}
+
+impl SetLocalInfileHandler for MysqlConnection {
+    fn set_local_infile_handler(&mut self, handler: Option<LocalInfileHandler>) {
+        mysql::Conn::set_local_infile_handler(self, handler)
+    }
+}
+
+impl SetLocalInfileHandler for mysql::Transaction<'_> {
+    fn set_local_infile_handler(&mut self, handler: Option<LocalInfileHandler>) {
+        mysql::Transaction::set_local_infile_handler(self, handler)
+    }
+}
+
+/// Create a local infile handler which only serves files within the whitelisted paths.
+/// A whitelisted path may be a file or a directory. Paths are compared after resolving symlinks and `..`,
+/// so a whitelisted directory can't be escaped
+pub fn whitelist_handler(paths: Vec<String>) -> LocalInfileHandler {
+    LocalInfileHandler::new(move |file_name, writer| {
+        let file_name = String::from_utf8_lossy(file_name).into_owned();
+        let requested = std::fs::canonicalize(&file_name)?;
+
+        let allowed = paths.iter()
+            .filter_map(|path| std::fs::canonicalize(path).ok())
+            .any(|path: PathBuf| requested.starts_with(path));
+        if !allowed {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, format!("Local infile '{}' is not whitelisted", file_name)));
+        }
+
+        let mut file = File::open(&requested)?;
+        io::copy(&mut file, writer)?;
+        Ok(())
+    })
+}
+
+/// The failure of a [stream_handler] to read its InputStream, if any
+#[derive(Clone, Default)]
+pub struct StreamError(Arc<Mutex<Option<io::Error>>>);
+
+impl StreamError {
+    fn set(&self, e: io::Error) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(e);
+    }
+
+    pub fn take(&self) -> Option<io::Error> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+/// Create a local infile handler which serves the contents of a Java InputStream, regardless of the requested file name.
+/// The handler must only be used on the thread which created it, while the InputStream is still in scope.
+///
+/// If reading the InputStream fails, the handler still ends the exchange with the server, as the connection would otherwise be out of sync.
+/// The server then loads the data sent up to that point, so the statement has to be rolled back. The failure is reported through the [StreamError]
+pub fn stream_handler(env: JNIEnv, input: JObject) -> Result<(LocalInfileHandler, StreamError), jni::errors::Error> {
+    let vm = env.get_java_vm()?;
+    let input = env.new_global_ref(input)?;
+    let error = StreamError::default();
+
+    let handler_error = error.clone();
+    let handler = LocalInfileHandler::new(move |_, writer| {
+        let failed = |e: jni::errors::Error| {
+            handler_error.set(io::Error::other(format!("Failed to read from InputStream: {:?}", e)));
+            Ok(())
+        };
+
+        // The handler runs on the thread executing the statement, which is already attached
+        let env = match vm.attach_current_thread() {
+            Ok(env) => env,
+            Err(e) => return failed(e)
+        };
+        let java_buf = match env.new_byte_array(CHUNK_SIZE) {
+            Ok(buf) => buf,
+            Err(e) => return failed(e)
+        };
+
+        copy_chunks(|buf| read_chunk(&env, &input, java_buf, buf), writer, &handler_error)
+    });
+
+    Ok((handler, error))
+}
+
+/// Read the next chunk of the Java InputStream into `buf`. Returns `None` at the end of the stream.
+/// If the InputStream throws, its exception is left pending
+fn read_chunk(env: &JNIEnv, input: &GlobalRef, java_buf: jbyteArray, buf: &mut [u8]) -> io::Result<Option<usize>> {
+    let jni_error = |e: jni::errors::Error| io::Error::other(format!("Failed to read from InputStream: {:?}", e));
+
+    let read = env.call_method(input.as_obj(), "read", "([BII)I", &[JValue::Object(java_buf.into()), JValue::Int(0), JValue::Int(buf.len() as i32)])
+        .and_then(|x| x.i())
+        .map_err(jni_error)?;
+    if read < 0 {
+        return Ok(None);
+    }
+
+    let mut chunk = vec![0i8; read as usize];
+    env.get_byte_array_region(java_buf, 0, &mut chunk).map_err(jni_error)?;
+    for (to, from) in buf.iter_mut().zip(chunk) {
+        *to = from as u8;
+    }
+
+    Ok(Some(read as usize))
+}
+
+/// Copy chunks from `read` to the writer until it returns `None`.
+/// A failure to read is stored in `error` and ends the copy early, rather than being returned, so the exchange with the server is still finished.
+/// A failure to write means the connection itself failed, so that is returned
+fn copy_chunks<R>(mut read: R, writer: &mut dyn Write, error: &StreamError) -> io::Result<()>
+where
+    R: FnMut(&mut [u8]) -> io::Result<Option<usize>>
+{
+    let mut buf = vec![0u8; CHUNK_SIZE as usize];
+    loop {
+        let len = match read(&mut buf) {
+            Ok(Some(len)) => len,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                error.set(e);
+                return Ok(());
+            }
+        };
+
+        writer.write_all(&buf[..len])?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_failure_ends_copy() {
+        let error = StreamError::default();
+        let mut chunks = vec![Ok(Some(3)), Err(io::Error::other("stream closed")), Ok(Some(3))].into_iter();
+        let mut out = Vec::new();
+
+        let result = copy_chunks(|buf| {
+            buf[..3].copy_from_slice(b"a,b");
+            chunks.next().unwrap()
+        }, &mut out, &error);
+
+        assert!(result.is_ok());
+        assert_eq!(out, b"a,b");
+        assert_eq!(error.take().unwrap().to_string(), "stream closed");
+    }
+
+    #[test]
+    fn copies_until_end_of_stream() {
+        let error = StreamError::default();
+        let mut chunks = vec![Ok(Some(2)), Ok(Some(1)), Ok(None)].into_iter();
+        let mut out = Vec::new();
+
+        copy_chunks(|buf| {
+            buf[..2].copy_from_slice(b"ab");
+            chunks.next().unwrap()
+        }, &mut out, &error).unwrap();
+
+        assert_eq!(out, b"aba");
+        assert!(error.take().is_none());
+    }
+
+    #[test]
+    fn write_failure_is_returned() {
+        struct Closed;
+        impl Write for Closed {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let error = StreamError::default();
+        assert!(copy_chunks(|_| Ok(Some(1)), &mut Closed, &error).is_err());
+        assert!(error.take().is_none());
+    }
+}
@@ -1,4 +1,16 @@
+mod begin_transaction;
 mod execute;
+mod execute_batch;
+mod generated_keys;
 mod initialize;
+mod load_data;
+mod local_infile;
+mod pool;
+mod pool_state;
 mod query;
-mod unload;
\ No newline at end of file
+mod query_columnar;
+mod query_stream;
+mod result_set;
+mod tls;
+mod transaction;
+mod unload;
@@ -0,0 +1,126 @@
+use std::ops::{Deref, DerefMut};
+use mysql::{Binary, Conn, Opts, QueryResult, Statement, Text};
+use mysql::prelude::{AsStatement, Queryable};
+use r2d2::{ManageConnection, Pool};
+
+/// Minimum number of idle connections when none is configured, matching the defaults of `mysql::Pool`
+pub const DEFAULT_MIN_POOL_SIZE: u32 = 10;
+/// Maximum number of connections when none is configured, matching the defaults of `mysql::Pool`
+pub const DEFAULT_MAX_POOL_SIZE: u32 = 100;
+
+/// The connection pool backing a MysqlDriver
+pub type MysqlPool = Pool<MysqlConnectionManager>;
+
+/// r2d2 connection manager for MySQL connections.
+/// We use r2d2 rather than `mysql::Pool`, as the latter has no support for idle timeouts, connection lifetimes or reporting its state
+pub struct MysqlConnectionManager {
+    opts: Opts,
+    /// Whether to reset the session state on checkout, rather than only pinging the connection
+    reset: bool,
+}
+
+/// A pooled MySQL connection. Remembers if a statement failed with an I/O error, so the connection is closed rather than returned to the pool
+pub struct MysqlConnection {
+    conn: Conn,
+    broken: bool,
+}
+
+impl MysqlConnection {
+    /// Mark the connection as broken if the result is an error after which it can no longer be used
+    fn check<T>(&mut self, result: mysql::Result<T>) -> mysql::Result<T> {
+        if let Err(e) = &result {
+            self.broken |= is_io_error(e);
+        }
+
+        result
+    }
+}
+
+/// Check if an error was caused by the connection itself, rather than by the statement
+fn is_io_error(e: &mysql::Error) -> bool {
+    matches!(e, mysql::Error::IoError(_) | mysql::Error::CodecError(_))
+}
+
+impl Deref for MysqlConnection {
+    type Target = Conn;
+
+    fn deref(&self) -> &Conn {
+        &self.conn
+    }
+}
+
+impl DerefMut for MysqlConnection {
+    fn deref_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+}
+
+impl Queryable for MysqlConnection {
+    fn query_iter<Q: AsRef<str>>(&mut self, query: Q) -> mysql::Result<QueryResult<'_, '_, '_, Text>> {
+        match self.conn.query_iter(query) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                self.broken |= is_io_error(&e);
+                Err(e)
+            }
+        }
+    }
+
+    fn prep<Q: AsRef<str>>(&mut self, query: Q) -> mysql::Result<Statement> {
+        let result = self.conn.prep(query);
+        self.check(result)
+    }
+
+    fn close(&mut self, stmt: Statement) -> mysql::Result<()> {
+        let result = self.conn.close(stmt);
+        self.check(result)
+    }
+
+    fn exec_iter<S: AsStatement, P: Into<mysql::Params>>(&mut self, stmt: S, params: P) -> mysql::Result<QueryResult<'_, '_, '_, Binary>> {
+        match self.conn.exec_iter(stmt, params) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                self.broken |= is_io_error(&e);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl MysqlConnectionManager {
+    pub fn new(opts: Opts, reset: bool) -> Self {
+        Self {
+            opts,
+            reset,
+        }
+    }
+}
+
+impl ManageConnection for MysqlConnectionManager {
+    type Connection = MysqlConnection;
+    type Error = mysql::Error;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        Conn::new(self.opts.clone()).map(|conn| MysqlConnection {
+            conn,
+            broken: false,
+        })
+    }
+
+    /// Run when a connection is checked out. A ping is enough to tell the connection is alive.
+    /// Transactions are rolled back when they are dropped, so session state is only left behind by statements such as `SET @var`.
+    /// Resetting clears it and re-runs the init commands, at the cost of more round trips, so it is only done if enabled
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        let result = if self.reset {
+            conn.conn.reset()
+        } else {
+            conn.conn.ping()
+        };
+
+        conn.check(result)
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        conn.broken
+    }
+}
@@ -0,0 +1,41 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JValue};
+use jni::sys::{jlong, jobject};
+use std::ptr::null_mut;
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     poolStateNative
+ * - Signature:  `(J)Ldev/array21/jdbd/datatypes/PoolState;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_poolStateNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let state = pool.state();
+
+        let args = [
+            JValue::Int(state.connections as i32),
+            JValue::Int(state.idle_connections as i32),
+            JValue::Int(pool.max_size() as i32),
+        ];
+
+        match env.new_object("dev/array21/jdbd/datatypes/PoolState", "(III)V", &args) {
+            Ok(s) => s.into_inner(),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create new dev.array21.jdbd.datatypes.PoolState: {:?}", e));
+                null_mut()
+            }
+        }
+    })
+}
@@ -1,12 +1,16 @@
-use jni::sys::{jobjectArray, jlong};
+use jni::sys::{jobject, jobjectArray, jlong};
 use jni::objects::{JObject, JString, JClass};
-use mysql::{Pool, Params, Row};
-use crate::jni::util::{set_error, Java};
+use mysql::{Params, Row, Value};
+use crate::jni::util::{Java, catch_panic, in_local_frame, to_sql_row_array};
 use jni::JNIEnv;
 use std::ptr::null_mut;
-use mysql::prelude::Queryable;
+use mysql::prelude::{FromValue, Queryable};
 use mysql::consts::ColumnType;
-use crate::jni::common::SqlParameterArray;
+use time::{Date, Duration, Month};
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
 use crate::unwrap_nullptr;
 
 /**
@@ -15,134 +19,226 @@ use crate::unwrap_nullptr;
  * - Signature:  `(JLjava/lang/String;)[Ldev/array21/jdbd/datatypes/SqlRow;`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_queryNative(env: JNIEnv, obj: JObject<'_>, pool_ptr: jlong, stmt: JString, params: SqlParameterArray) -> jobjectArray {
-    let pool_ptr = pool_ptr as *mut Pool;
-    let mut conn = match unsafe { &*pool_ptr }.get_conn() {
-        Ok(c) => c,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to create MySQL connection: {:?}", e));
-            return null_mut();
-        }
-    };
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_queryNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        query(env, &mut *conn, stmt, params)
+    })
+}
 
+/// Run a query on a MySQL connection or transaction
+pub fn query<Q: Queryable>(env: JNIEnv, conn: &mut Q, stmt: JString, params: SqlParameterArray) -> jobjectArray {
     let stmt = match env.get_string(stmt) {
         Ok(s) => String::from(s),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
             return null_mut()
         }
     };
 
-    let sql_params_len = match env.get_array_length(params) {
+    let sql_params_rust = match read_sql_parameters(&env, params) {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to get params array length: {:?}", e));
+            throw(env, SQL_EXCEPTION, &e);
             return null_mut();
         }
     };
-
-    let sql_params_java = match (0..sql_params_len).into_iter()
-        .map(|x| env.get_object_array_element(params, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
+    let sql_params_mysql = match sql_params_rust.into_iter()
+        .map(mysql::Value::try_from)
+        .collect::<Result<Vec<_>, String>>() {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to fetch element of sql params array: {:?}", e));
+            throw(env, SQL_EXCEPTION, &e);
             return null_mut();
         }
     };
 
-    let sql_params_rust = match sql_params_java.into_iter()
-        .map(|x| crate::jni::common::into_sql_parameter(&env, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to convert sql params element to Rust type: {:?}", e));
-            return null_mut();
-        }
-    };
-    let sql_params_mysql = sql_params_rust.into_iter()
-        .map(|x| x.into())
-        .collect::<Vec<mysql::Value>>();
-
     let result = match conn.exec::<Row, &str, Params>(&stmt, Params::Positional(sql_params_mysql)) {
         Ok(r) => r,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to execute stmt: {:?}", e));
+            SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
             return null_mut();
         }
     };
 
-    // Vec of dev.array21.jdbd.datatypes.SqlRow
-    let mut sqlrows = Vec::new();
+    to_sql_row_array(env, &result, to_sql_row)
+}
 
-    for row in result {
-        // Vec of java.lang.Class
-        let mut classes: Vec<JClass> = Vec::new();
-        // Vec of java.lang.Object
-        let mut objects: Vec<JObject> = Vec::new();
-        let mut names: Vec<String> = Vec::new();
+/// Convert a MySQL row to a dev.array21.jdbd.datatypes.SqlRow. Returns a nullptr if an exception was thrown
+pub fn to_sql_row(env: JNIEnv, row: &Row) -> jobject {
+    // Each value creates a local reference, and each column name another. They are freed with the frame, so they don't pile up over many rows
+    in_local_frame(env, 2 * row.len() as i32 + 8, || new_sql_row(env, row))
+}
 
-        for col in row.columns().iter() {
-            let name = col.name_str().to_string();
-            match col.column_type() {
-                ColumnType::MYSQL_TYPE_STRING | ColumnType::MYSQL_TYPE_VAR_STRING | ColumnType::MYSQL_TYPE_VARCHAR => {
-                    let v: Option<String> = row.get(&*name).unwrap();
-                    classes.push(unwrap_nullptr!(env, obj, Java::String(env)));
-                    names.push(name);
-                    match v {
-                        Some(v) => {
-                            let string = unwrap_nullptr!(env, obj, Java::new_String(env, v));
-                            objects.push(string.into());
-                        }
-                        None => objects.push(JObject::null())
+fn new_sql_row(env: JNIEnv, row: &Row) -> jobject {
+    // Vec of java.lang.Class
+    let mut classes: Vec<JClass> = Vec::new();
+    // Vec of java.lang.Object
+    let mut objects: Vec<JObject> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+
+    for (index, col) in row.columns().iter().enumerate() {
+        let name = col.name_str().to_string();
+        match col.column_type() {
+            ColumnType::MYSQL_TYPE_STRING | ColumnType::MYSQL_TYPE_VAR_STRING | ColumnType::MYSQL_TYPE_VARCHAR => {
+                let v: Option<String> = match get(row, index, &name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(&name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::String(env)));
+                names.push(name);
+                match v {
+                    Some(v) => {
+                        let string = unwrap_nullptr!(env, Java::new_String(env, v));
+                        objects.push(string.into());
                     }
-                },
-                ColumnType::MYSQL_TYPE_INT24 | ColumnType::MYSQL_TYPE_LONG | ColumnType::MYSQL_TYPE_LONGLONG => {
-                    let v: Option<i64> = row.get(&*name).unwrap();
-                    classes.push(unwrap_nullptr!(env, obj, Java::Long(env)));
-                    names.push(name);
-                    match v {
-                        Some(v) => {
-                            let long = unwrap_nullptr!(env, obj, Java::new_Long(env, v));
-                            objects.push(long);
-                        },
-                        None => objects.push(JObject::null())
+                    None => objects.push(JObject::null())
+                }
+            },
+            ColumnType::MYSQL_TYPE_INT24 | ColumnType::MYSQL_TYPE_LONG | ColumnType::MYSQL_TYPE_LONGLONG | ColumnType::MYSQL_TYPE_YEAR => {
+                let v: Option<i64> = match get(row, index, &name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(&name, e).throw(env);
+                        return null_mut();
                     }
-                },
-                ColumnType::MYSQL_TYPE_DOUBLE | ColumnType::MYSQL_TYPE_FLOAT => {
-                    let v: Option<f64> = row.get(&*name).unwrap();
-                    classes.push(unwrap_nullptr!(env, obj, Java::Double(env)));
-                    names.push(name);
-                    match v {
-                        Some(v) => {
-                            let double = unwrap_nullptr!(env, obj, Java::new_Double(env, v));
-                            objects.push(double);
-                        },
-                        None => objects.push(JObject::null())
+                };
+                classes.push(unwrap_nullptr!(env, Java::Long(env)));
+                names.push(name);
+                match v {
+                    Some(v) => {
+                        let long = unwrap_nullptr!(env, Java::new_Long(env, v));
+                        objects.push(long);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            ColumnType::MYSQL_TYPE_DOUBLE | ColumnType::MYSQL_TYPE_FLOAT => {
+                let v: Option<f64> = match get(row, index, &name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(&name, e).throw(env);
+                        return null_mut();
                     }
-                },
-                ColumnType::MYSQL_TYPE_BLOB | ColumnType::MYSQL_TYPE_LONG_BLOB | ColumnType::MYSQL_TYPE_MEDIUM_BLOB | ColumnType::MYSQL_TYPE_TINY_BLOB => {
-                    let v: Option<Vec<u8>> = row.get(&*name).unwrap();
-                    classes.push(unwrap_nullptr!(env, obj, Java::Byte_array(env)));
-                    names.push(name);
-                    match v {
-                        Some(v) => {
-                            let byte_array = unwrap_nullptr!(env, obj, Java::new_Byte_array_u8(env, v));
-                            objects.push(byte_array);
-                        },
-                        None => objects.push(JObject::null())
+                };
+                classes.push(unwrap_nullptr!(env, Java::Double(env)));
+                names.push(name);
+                match v {
+                    Some(v) => {
+                        let double = unwrap_nullptr!(env, Java::new_Double(env, v));
+                        objects.push(double);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            ColumnType::MYSQL_TYPE_BLOB | ColumnType::MYSQL_TYPE_LONG_BLOB | ColumnType::MYSQL_TYPE_MEDIUM_BLOB | ColumnType::MYSQL_TYPE_TINY_BLOB => {
+                let v: Option<Vec<u8>> = match get(row, index, &name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(&name, e).throw(env);
+                        return null_mut();
                     }
+                };
+                classes.push(unwrap_nullptr!(env, Java::byte_array(env)));
+                names.push(name);
+                match v {
+                    Some(v) => {
+                        let byte_array = unwrap_nullptr!(env, Java::new_byte_array(env, &v));
+                        objects.push(byte_array);
+                    },
+                    None => objects.push(JObject::null())
                 }
-                _ => unimplemented!()
+            }
+            ColumnType::MYSQL_TYPE_DATE | ColumnType::MYSQL_TYPE_NEWDATE => {
+                classes.push(unwrap_nullptr!(env, Java::LocalDate(env)));
+                names.push(name);
+                objects.push(unwrap_nullptr!(env, new_temporal(env, col.column_type(), row.as_ref(index))));
+            },
+            ColumnType::MYSQL_TYPE_DATETIME | ColumnType::MYSQL_TYPE_DATETIME2 => {
+                classes.push(unwrap_nullptr!(env, Java::LocalDateTime(env)));
+                names.push(name);
+                objects.push(unwrap_nullptr!(env, new_temporal(env, col.column_type(), row.as_ref(index))));
+            },
+            ColumnType::MYSQL_TYPE_TIMESTAMP | ColumnType::MYSQL_TYPE_TIMESTAMP2 => {
+                classes.push(unwrap_nullptr!(env, Java::Instant(env)));
+                names.push(name);
+                objects.push(unwrap_nullptr!(env, new_temporal(env, col.column_type(), row.as_ref(index))));
+            },
+            ColumnType::MYSQL_TYPE_TIME | ColumnType::MYSQL_TYPE_TIME2 => {
+                classes.push(unwrap_nullptr!(env, Java::Duration(env)));
+                names.push(name);
+                objects.push(unwrap_nullptr!(env, new_temporal(env, col.column_type(), row.as_ref(index))));
+            },
+            other => {
+                SqlError::column(&name, format!("Unsupported type {:?} of column '{}'", other, name)).throw(env);
+                return null_mut();
             }
         }
+    }
+
+    // Type = dev.array21.jdbd.datatypes.SqlRow
+    let sqlrow_java = unwrap_nullptr!(env, Java::new_SqlRow(env, names, objects, classes));
+    sqlrow_java.into_inner()
+}
 
-        // Type = dev.array21.jdbd.datatypes.SqlRow
-        let sqlrow_java = unwrap_nullptr!(env, obj, Java::new_SqlRow(env, names, objects, classes));
-        sqlrows.push(sqlrow_java);
+/// Get the value in column `index` of `row`
+fn get<T: FromValue>(row: &Row, index: usize, name: &str) -> Result<Option<T>, String> {
+    match row.get_opt::<Option<T>, usize>(index) {
+        Some(Ok(v)) => Ok(v),
+        Some(Err(e)) => Err(format!("Failed to convert value of column '{}': {:?}", name, e)),
+        None => Err(format!("Row has no value for column '{}'", name)),
     }
+}
+
+/// Convert a DATE, DATETIME, TIMESTAMP or TIME value to its java.time counterpart.
+/// TIMESTAMP values are in UTC, as that is the time zone of the connection
+fn new_temporal<'a>(env: JNIEnv<'a>, column_type: ColumnType, value: Option<&Value>) -> Result<JObject<'a>, String> {
+    match value {
+        None | Some(Value::NULL) => Ok(JObject::null()),
+        Some(&Value::Date(year, month, day, hour, minute, second, micros)) => {
+            if month == 0 || day == 0 {
+                return Err(format!("Date '{:04}-{:02}-{:02}' has a zero month or day, which can't be represented by java.time", year, month, day));
+            }
 
-    // Convert the Vec of dev.array21.jdbd.datatypes.SqlRow to dev.array21.jdbd.datatypes.SqlRow[]
-    let sqlrow_java_array = unwrap_nullptr!(env, obj, Java::new_SqlRow_array(env, sqlrows));
-    sqlrow_java_array.into_inner()
+            let date_time = match Month::try_from(month)
+                .and_then(|month| Date::from_calendar_date(year as i32, month, day))
+                .and_then(|date| date.with_hms_micro(hour, minute, second, micros)) {
+                Ok(date_time) => date_time,
+                Err(e) => return Err(format!("Invalid date '{:04}-{:02}-{:02}': {}", year, month, day, e))
+            };
+
+            match column_type {
+                ColumnType::MYSQL_TYPE_DATE | ColumnType::MYSQL_TYPE_NEWDATE => Java::new_LocalDate(env, date_time.date()),
+                ColumnType::MYSQL_TYPE_TIMESTAMP | ColumnType::MYSQL_TYPE_TIMESTAMP2 => Java::new_Instant(env, date_time.assume_utc()),
+                _ => Java::new_LocalDateTime(env, date_time)
+            }
+        },
+        Some(&Value::Time(negative, days, hours, minutes, seconds, micros)) => {
+            let duration = Duration::days(days as i64)
+                + Duration::hours(hours as i64)
+                + Duration::minutes(minutes as i64)
+                + Duration::seconds(seconds as i64)
+                + Duration::microseconds(micros as i64);
+
+            Java::new_Duration(env, if negative { -duration } else { duration })
+        },
+        Some(other) => Err(format!("Unexpected value {:?} for a column of type {:?}", other, column_type))
+    }
 }
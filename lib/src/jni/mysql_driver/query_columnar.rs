@@ -0,0 +1,137 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::jlong;
+use std::sync::Mutex;
+use mysql::{Params, Row};
+use mysql::consts::ColumnType;
+use mysql::prelude::{FromValue, Queryable};
+use crate::jni::util::catch_panic;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::columnar::{self, Column, ColumnKind};
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     queryColumnarNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_queryColumnarNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let stmt = match env.get_string(stmt) {
+            Ok(s) => String::from(s),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+                return 0;
+            }
+        };
+
+        let sql_params_rust = match read_sql_parameters(&env, params) {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let sql_params_mysql = match sql_params_rust.into_iter()
+            .map(mysql::Value::try_from)
+            .collect::<Result<Vec<_>, String>>() {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let mut conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return 0;
+            }
+        };
+
+        let mut result = match conn.exec_iter(stmt, Params::Positional(sql_params_mysql)) {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
+                return 0;
+            }
+        };
+
+        let mut columns = Vec::new();
+        for col in result.columns().as_ref() {
+            let kind = match col.column_type() {
+                ColumnType::MYSQL_TYPE_STRING | ColumnType::MYSQL_TYPE_VAR_STRING | ColumnType::MYSQL_TYPE_VARCHAR => ColumnKind::String,
+                ColumnType::MYSQL_TYPE_INT24 | ColumnType::MYSQL_TYPE_LONG | ColumnType::MYSQL_TYPE_LONGLONG | ColumnType::MYSQL_TYPE_YEAR => ColumnKind::Long,
+                ColumnType::MYSQL_TYPE_DOUBLE | ColumnType::MYSQL_TYPE_FLOAT => ColumnKind::Double,
+                ColumnType::MYSQL_TYPE_BLOB | ColumnType::MYSQL_TYPE_LONG_BLOB | ColumnType::MYSQL_TYPE_MEDIUM_BLOB | ColumnType::MYSQL_TYPE_TINY_BLOB => ColumnKind::Bytes,
+                other => {
+                    throw(env, SQL_EXCEPTION, &format!("Unsupported type {:?} of column '{}'", other, col.name_str()));
+                    return 0;
+                }
+            };
+
+            columns.push(Column::new(col.name_str().to_string(), kind));
+        }
+
+        let mut rows = 0;
+        for row in result.by_ref() {
+            let mut row = match row {
+                Ok(r) => r,
+                Err(e) => {
+                    SqlError::from_mysql("Failed to fetch row", &e).throw(env);
+                    return 0;
+                }
+            };
+
+            for (index, column) in columns.iter_mut().enumerate() {
+                if let Err(e) = push_value(column, &mut row, index) {
+                    throw(env, SQL_EXCEPTION, &e);
+                    return 0;
+                }
+            }
+
+            rows += 1;
+        }
+
+        match columnar::encode(&columns, rows) {
+            Ok(buf) => insert_handle(Mutex::new(buf)),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                0
+            }
+        }
+    })
+}
+
+/// Push the value in column `index` of `row` to `column`
+fn push_value(column: &mut Column, row: &mut Row, index: usize) -> Result<(), String> {
+    match column.kind() {
+        ColumnKind::Long => column.push_long(take(row, index, column.name())?),
+        ColumnKind::Double => column.push_double(take(row, index, column.name())?),
+        ColumnKind::String | ColumnKind::Bytes => column.push_bytes(take::<Vec<u8>>(row, index, column.name())?.as_deref())?,
+    }
+
+    Ok(())
+}
+
+/// Take the value in column `index` out of `row`
+fn take<T: FromValue>(row: &mut Row, index: usize, name: &str) -> Result<Option<T>, String> {
+    match row.take_opt::<Option<T>, usize>(index) {
+        Some(Ok(v)) => Ok(v),
+        Some(Err(e)) => Err(format!("Failed to convert value of column '{}': {:?}", name, e)),
+        None => Err(format!("Row has no value for column '{}'", name)),
+    }
+}
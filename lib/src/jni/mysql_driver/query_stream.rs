@@ -0,0 +1,75 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::jlong;
+use std::sync::Mutex;
+use mysql::Params;
+use mysql::prelude::Queryable;
+use crate::jni::util::catch_panic;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::pinned::Pinned;
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      MysqlDriver
+ * - Method:     queryStreamNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_queryStreamNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<MysqlPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let stmt = match env.get_string(stmt) {
+            Ok(s) => String::from(s),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+                return 0;
+            }
+        };
+
+        let sql_params_rust = match read_sql_parameters(&env, params) {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let sql_params_mysql = match sql_params_rust.into_iter()
+            .map(mysql::Value::try_from)
+            .collect::<Result<Vec<_>, String>>() {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let conn = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create MySQL connection: {:?}", e));
+                return 0;
+            }
+        };
+
+        // The result keeps the connection until it is closed, rows are only read from it when they are fetched
+        let result = match Pinned::begin(conn, |conn| conn.exec_iter(stmt, Params::Positional(sql_params_mysql))) {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_mysql("Failed to execute stmt", &e).throw(env);
+                return 0;
+            }
+        };
+
+        insert_handle(Mutex::new(result))
+    })
+}
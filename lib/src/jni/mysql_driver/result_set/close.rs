@@ -0,0 +1,33 @@
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use crate::jni::mysql_driver::result_set::MysqlResultSet;
+use crate::jni::handle::{get_handle, remove_handle};
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlResultSet
+ * - Method:     closeNative
+ * - Signature:  `(J)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlResultSet_closeNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        let result_set = match get_handle::<MysqlResultSet>(handle) {
+            Ok(r) => r,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get result set: {}", e));
+                return;
+            }
+        };
+
+        let _ = remove_handle::<MysqlResultSet>(handle);
+
+        // Dropping the result reads and discards the rows which have not been fetched, after which the connection returns to the pool
+        let closed = result_set.lock().unwrap_or_else(|e| e.into_inner()).finish(drop);
+        if closed.is_none() {
+            throw(env, INVALID_HANDLE_EXCEPTION, "Result set has already been closed");
+        }
+    })
+}
@@ -0,0 +1,46 @@
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::{jint, jlong, jobjectArray};
+use std::ptr::null_mut;
+use crate::jni::mysql_driver::result_set::MysqlResultSet;
+use crate::jni::mysql_driver::query::to_sql_row;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::{catch_panic, to_sql_row_array};
+
+/**
+ * - Class:      MysqlResultSet
+ * - Method:     fetchNative
+ * - Signature:  `(JI)[Ldev/array21/jdbd/datatypes/SqlRow;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlResultSet_fetchNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, count: jint) -> jobjectArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let result_set = match get_handle::<MysqlResultSet>(handle) {
+            Ok(r) => r,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get result set: {}", e));
+                return null_mut();
+            }
+        };
+        let mut guard = result_set.lock().unwrap_or_else(|e| e.into_inner());
+        let result = match guard.get() {
+            Some(r) => r,
+            None => {
+                throw(env, INVALID_HANDLE_EXCEPTION, "Result set has already been closed");
+                return null_mut();
+            }
+        };
+
+        // Fewer rows than requested are returned once the result is exhausted
+        let rows = match result.by_ref().take(count.max(0) as usize).collect::<Result<Vec<_>, _>>() {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_mysql("Failed to fetch row", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        to_sql_row_array(env, &rows, to_sql_row)
+    })
+}
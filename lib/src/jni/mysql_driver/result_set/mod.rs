@@ -0,0 +1,11 @@
+use std::sync::Mutex;
+use mysql::{Binary, QueryResult};
+use r2d2::PooledConnection;
+use crate::jni::mysql_driver::pool::MysqlConnectionManager;
+use crate::jni::pinned::Pinned;
+
+mod close;
+mod fetch;
+
+/// A streaming query result together with the pooled connection it is read from, backing a MysqlResultSet
+pub type MysqlResultSet = Mutex<Pinned<PooledConnection<MysqlConnectionManager>, QueryResult<'static, 'static, 'static, Binary>>>;
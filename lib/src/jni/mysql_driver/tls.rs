@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+use mysql::{ClientIdentity, SslOpts};
+
+/// TLS settings as configured on the Java MysqlDriver
+pub struct TlsOptions {
+    pub enabled: bool,
+    /// Path to a PEM or DER encoded CA certificate
+    pub ca_file: Option<String>,
+    /// Path to a PKCS#12 archive holding the client certificate and key
+    pub client_identity: Option<String>,
+    /// Password of the PKCS#12 archive
+    pub client_identity_password: Option<String>,
+    /// Do not validate the server's hostname against its certificate. For development only
+    pub skip_domain_validation: bool,
+}
+
+impl TlsOptions {
+    /// Create the SslOpts to configure the MySQL connection pool with.
+    /// Returns `None` if TLS is disabled
+    pub fn build(self) -> Option<SslOpts> {
+        if !self.enabled {
+            return None;
+        }
+
+        let client_identity = self.client_identity.map(|path| {
+            let identity = ClientIdentity::new(PathBuf::from(path));
+            match self.client_identity_password {
+                Some(password) => identity.with_password(password),
+                None => identity
+            }
+        });
+
+        let ssl_opts = SslOpts::default()
+            .with_root_cert_path(self.ca_file.map(PathBuf::from))
+            .with_client_identity(client_identity)
+            .with_danger_skip_domain_validation(self.skip_domain_validation);
+
+        Some(ssl_opts)
+    }
+}
@@ -0,0 +1,37 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jobject};
+use crate::jni::mysql_driver::transaction::MysqlTransaction;
+use crate::jni::mysql_driver::execute::execute;
+use crate::jni::common::SqlParameterArray;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlTransaction
+ * - Method:     executeNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)Ldev/array21/jdbd/datatypes/ExecuteResult;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlTransaction_executeNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, std::ptr::null_mut(), || {
+        let transaction = match get_handle::<MysqlTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return std::ptr::null_mut();
+            }
+        };
+        let mut guard = transaction.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = match guard.get() {
+            Some(t) => t,
+            None => {
+                throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back");
+                return std::ptr::null_mut();
+            }
+        };
+
+        execute(env, transaction, stmt, params)
+    })
+}
\ No newline at end of file
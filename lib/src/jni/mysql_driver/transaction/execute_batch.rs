@@ -0,0 +1,38 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jlongArray};
+use std::ptr::null_mut;
+use crate::jni::mysql_driver::transaction::MysqlTransaction;
+use crate::jni::mysql_driver::execute_batch::execute_batch;
+use crate::jni::common::SqlParameterSetArray;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlTransaction
+ * - Method:     executeBatchNative
+ * - Signature:  `(JLjava/lang/String;[[Ldev/array21/jdbd/datatypes/SqlParameter;)[J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlTransaction_executeBatchNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, param_sets: SqlParameterSetArray) -> jlongArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let transaction = match get_handle::<MysqlTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return null_mut();
+            }
+        };
+        let mut guard = transaction.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = match guard.get() {
+            Some(t) => t,
+            None => {
+                throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back");
+                return null_mut();
+            }
+        };
+
+        execute_batch(env, transaction, stmt, param_sets)
+    })
+}
@@ -0,0 +1,55 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jobject};
+use std::ptr::null_mut;
+use mysql::prelude::Queryable;
+use crate::jni::mysql_driver::transaction::MysqlTransaction;
+use crate::jni::mysql_driver::load_data::load_data;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/// Savepoint around a load, so it can be undone on its own
+const LOAD_DATA_SAVEPOINT: &str = "jdbd_load_data";
+
+/**
+ * - Class:      MysqlTransaction
+ * - Method:     loadDataNative
+ * - Signature:  `(JLjava/lang/String;Ljava/io/InputStream;)Ldev/array21/jdbd/datatypes/ExecuteResult;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlTransaction_loadDataNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, input: JObject) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let transaction = match get_handle::<MysqlTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return null_mut();
+            }
+        };
+        let mut guard = transaction.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = match guard.get() {
+            Some(t) => t,
+            None => {
+                throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back");
+                return null_mut();
+            }
+        };
+
+        // The rows loaded before the InputStream failed are undone by rolling back to the savepoint, which keeps the transaction itself usable
+        if let Err(e) = transaction.query_drop(format!("SAVEPOINT {}", LOAD_DATA_SAVEPOINT)) {
+            SqlError::from_mysql("Failed to create savepoint", &e).throw(env);
+            return null_mut();
+        }
+
+        let result = load_data(env, transaction, stmt, input);
+        let statement = if result.is_null() { "ROLLBACK TO SAVEPOINT" } else { "RELEASE SAVEPOINT" };
+        if let Err(e) = transaction.query_drop(format!("{} {}", statement, LOAD_DATA_SAVEPOINT)) {
+            // An exception of the load itself may be pending, throw() keeps it as the cause
+            throw(env, SQL_EXCEPTION, &format!("Failed to run '{} {}': {}", statement, LOAD_DATA_SAVEPOINT, e));
+            return null_mut();
+        }
+
+        result
+    })
+}
@@ -0,0 +1,62 @@
+use std::sync::Mutex;
+use jni::JNIEnv;
+use jni::objects::JString;
+use jni::sys::jlong;
+use mysql::prelude::Queryable;
+use r2d2::PooledConnection;
+use crate::jni::mysql_driver::pool::MysqlConnectionManager;
+use crate::jni::pinned::Pinned;
+use crate::jni::common::is_valid_savepoint_name;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+mod commit;
+mod execute;
+mod execute_batch;
+mod generated_keys;
+mod load_data;
+mod query;
+mod release_savepoint;
+mod rollback;
+mod rollback_to_savepoint;
+mod savepoint;
+
+/// A transaction together with the pooled connection it runs on, backing a MysqlTransaction
+pub type MysqlTransaction = Mutex<Pinned<PooledConnection<MysqlConnectionManager>, mysql::Transaction<'static>>>;
+
+/// Run a savepoint statement, e.g. `SAVEPOINT` or `RELEASE SAVEPOINT`, for the savepoint `name` on the transaction behind `handle`
+fn savepoint_statement(env: JNIEnv, handle: jlong, command: &str, name: JString) {
+    let name: String = match env.get_string(name) {
+        Ok(s) => s.into(),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert savepoint name from JString to String: {:?}", e));
+            return;
+        }
+    };
+
+    if !is_valid_savepoint_name(&name) {
+        throw(env, SQL_EXCEPTION, &format!("Invalid savepoint name '{}', only letters, digits and underscores are allowed", name));
+        return;
+    }
+
+    let transaction = match get_handle::<MysqlTransaction>(handle) {
+        Ok(t) => t,
+        Err(e) => {
+            throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+            return;
+        }
+    };
+    let mut guard = transaction.lock().unwrap_or_else(|e| e.into_inner());
+    let transaction = match guard.get() {
+        Some(t) => t,
+        None => {
+            throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back");
+            return;
+        }
+    };
+
+    let statement = format!("{} {}", command, name);
+    if let Err(e) = transaction.query_drop(&statement) {
+        SqlError::from_mysql(&format!("Failed to run '{}'", statement), &e).throw(env);
+    }
+}
@@ -0,0 +1,35 @@
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use crate::jni::mysql_driver::transaction::MysqlTransaction;
+use crate::jni::handle::{get_handle, remove_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlTransaction
+ * - Method:     rollbackNative
+ * - Signature:  `(J)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlTransaction_rollbackNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        let transaction = match get_handle::<MysqlTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return;
+            }
+        };
+
+        // The transaction is over after this, whether it succeeds or not
+        let _ = remove_handle::<MysqlTransaction>(handle);
+
+        let result = transaction.lock().unwrap_or_else(|e| e.into_inner()).finish(|t| t.rollback());
+        match result {
+            Some(Ok(_)) => {},
+            Some(Err(e)) => SqlError::from_mysql("Failed to roll back transaction", &e).throw(env),
+            None => throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back")
+        }
+    })
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::jlong;
+use crate::jni::mysql_driver::transaction::savepoint_statement;
+use crate::jni::error::SQL_EXCEPTION;
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      MysqlTransaction
+ * - Method:     savepointNative
+ * - Signature:  `(JLjava/lang/String;)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlTransaction_savepointNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, name: JString) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        savepoint_statement(env, handle, "SAVEPOINT", name)
+    })
+}
\ No newline at end of file
@@ -1,6 +1,10 @@
-use jni::sys::{JNIEnv, jlong};
+use jni::JNIEnv;
+use jni::sys::jlong;
 use jni::objects::JObject;
-use mysql::Pool;
+use crate::jni::mysql_driver::pool::MysqlPool;
+use crate::jni::handle::remove_handle;
+use crate::jni::error::{throw, DRIVER_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
 
 /**
  * - Class:      MysqlDriver
@@ -8,8 +12,10 @@ use mysql::Pool;
  * - Signature:  `(J)V`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_unloadNative(_env: JNIEnv, _obj: JObject<'_>, ptr: jlong) {
-    let pool_ptr = ptr as *mut Pool;
-    let pool = unsafe { Box::from_raw(pool_ptr) };
-    drop(pool);
-}
\ No newline at end of file
+pub extern "system" fn Java_dev_array21_jdbd_drivers_MysqlDriver_unloadNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, DRIVER_EXCEPTION, (), || {
+        if let Err(e) = remove_handle::<MysqlPool>(handle) {
+            throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to unload driver: {}", e));
+        }
+    })
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+//! A transaction or a streaming result borrows the connection it runs on, but Java has to hold on to both through a single handle.
+//! [Pinned] moves the connection to the heap, so its address never changes,
+//! and keeps the value borrowing it next to it.
+
+use std::ptr::NonNull;
+
+pub struct Pinned<C: 'static, T: 'static> {
+    /// Borrows `conn`, so it must always be dropped before `conn` is
+    value: Option<T>,
+    /// Owned, created with `Box::into_raw`. `None` once the value has been finished
+    conn: Option<NonNull<C>>,
+}
+
+// SAFETY: The value and the connection it borrows are owned by this struct, so they always move between threads together
+unsafe impl<C: Send, T: Send> Send for Pinned<C, T> {}
+
+impl<C, T> Pinned<C, T> {
+    /// Move the connection to the heap and create the value borrowing it, e.g. by beginning a transaction on it
+    pub fn begin<E, F: FnOnce(&'static mut C) -> Result<T, E>>(conn: C, begin: F) -> Result<Self, E> {
+        let conn = NonNull::from(Box::leak(Box::new(conn)));
+
+        // SAFETY: The connection is only freed after the value borrowing it has been dropped
+        match begin(unsafe { &mut *conn.as_ptr() }) {
+            Ok(value) => Ok(Self {
+                value: Some(value),
+                conn: Some(conn),
+            }),
+            Err(e) => {
+                // SAFETY: `begin` failed, so nothing borrows the connection anymore
+                drop(unsafe { Box::from_raw(conn.as_ptr()) });
+                Err(e)
+            }
+        }
+    }
+
+    /// Get the value. Returns `None` if it has already been finished
+    pub fn get(&mut self) -> Option<&mut T> {
+        self.value.as_mut()
+    }
+
+    /// Finish the value with `f`, e.g. by committing the transaction, and release the connection.
+    /// Returns `None` if the value has already been finished
+    pub fn finish<R, F: FnOnce(T) -> R>(&mut self, f: F) -> Option<R> {
+        let result = self.value.take().map(f);
+        self.release();
+        result
+    }
+
+    fn release(&mut self) {
+        // Make sure the value is gone before the connection it borrows
+        self.value = None;
+        if let Some(conn) = self.conn.take() {
+            // SAFETY: The value has been dropped, so nothing borrows the connection anymore
+            drop(unsafe { Box::from_raw(conn.as_ptr()) });
+        }
+    }
+}
+
+impl<C, T> Drop for Pinned<C, T> {
+    fn drop(&mut self) {
+        // Dropping an unfinished transaction rolls it back, dropping an unfinished result discards its remaining rows
+        self.release();
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Mutex;
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use postgres::IsolationLevel;
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::{get_boolean_field, get_enum_field, catch_panic};
+use crate::jni::pinned::Pinned;
+use crate::unwrap_zero;
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     beginTransactionNative
+ * - Signature:  `(JLdev/array21/jdbd/datatypes/TransactionOptions;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_beginTransactionNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, options: JObject<'_>) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let isolation_level = unwrap_zero!(get_enum_field(env, options, "isolationLevel", "Ldev/array21/jdbd/datatypes/IsolationLevel;"));
+        let read_only = unwrap_zero!(get_boolean_field(env, options, "readOnly"));
+        let deferrable = unwrap_zero!(get_boolean_field(env, options, "deferrable"));
+
+        let isolation_level = match isolation_level.as_deref() {
+            Some("READ_UNCOMMITTED") => Some(IsolationLevel::ReadUncommitted),
+            Some("READ_COMMITTED") => Some(IsolationLevel::ReadCommitted),
+            Some("REPEATABLE_READ") => Some(IsolationLevel::RepeatableRead),
+            Some("SERIALIZABLE") => Some(IsolationLevel::Serializable),
+            Some(level) => {
+                throw(env, SQL_EXCEPTION, &format!("Invalid isolation level '{}'", level));
+                return 0;
+            },
+            None => None
+        };
+
+        let client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        let transaction = Pinned::begin(client, |client| {
+            let mut builder = client.build_transaction();
+            if let Some(isolation_level) = isolation_level {
+                builder = builder.isolation_level(isolation_level);
+            }
+
+            if read_only {
+                builder = builder.read_only(true);
+            }
+
+            if deferrable {
+                builder = builder.deferrable(true);
+            }
+
+            builder.start()
+        });
+
+        let transaction = match transaction {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_postgres("Failed to begin transaction", &e).throw(env);
+                return 0;
+            }
+        };
+
+        insert_handle(Mutex::new(transaction))
+    })
+}
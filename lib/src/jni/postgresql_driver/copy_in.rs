@@ -0,0 +1,108 @@
+use std::io::Write;
+use jni::JNIEnv;
+use jni::objects::{JObject, JString, JValue};
+use jni::sys::jlong;
+use postgres::GenericClient;
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::postgresql_driver::copy_io_error;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/// Number of bytes read from the Java InputStream at a time
+const CHUNK_SIZE: i32 = 64 * 1024;
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     copyInNative
+ * - Signature:  `(JLjava/lang/String;Ljava/io/InputStream;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_copyInNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, input: JObject) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        copy_in(env, &mut *client, stmt, input)
+    })
+}
+
+/// Run a `COPY ... FROM STDIN` statement on a PostgreSQL connection or transaction, streaming the data from a Java InputStream.
+/// Returns the number of rows copied. If reading from the InputStream fails, the copy is aborted and the Java exception is left pending
+pub fn copy_in<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, input: JObject) -> jlong {
+    let stmt: String = match env.get_string(stmt) {
+        Ok(s) => s.into(),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return 0;
+        }
+    };
+
+    let java_buf = match env.new_byte_array(CHUNK_SIZE) {
+        Ok(b) => b,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to create byte[]: {:?}", e));
+            return 0;
+        }
+    };
+
+    // Dropping the writer without finishing it aborts the copy
+    let mut writer = match client.copy_in(&*stmt) {
+        Ok(w) => w,
+        Err(e) => {
+            SqlError::from_postgres("Failed to start copy", &e).throw(env);
+            return 0;
+        }
+    };
+
+    let mut chunk = vec![0i8; CHUNK_SIZE as usize];
+    let mut bytes = Vec::with_capacity(CHUNK_SIZE as usize);
+    loop {
+        let read = match env.call_method(input, "read", "([BII)I", &[JValue::Object(java_buf.into()), JValue::Int(0), JValue::Int(CHUNK_SIZE)]).and_then(|x| x.i()) {
+            Ok(n) => n,
+            // The IOException thrown by the InputStream is propagated to the caller
+            Err(jni::errors::Error::JavaException) => return 0,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to read from InputStream: {:?}", e));
+                return 0;
+            }
+        };
+
+        if read < 0 {
+            break;
+        }
+
+        if let Err(e) = env.get_byte_array_region(java_buf, 0, &mut chunk[..read as usize]) {
+            throw(env, SQL_EXCEPTION, &format!("Failed to get byte[] region: {:?}", e));
+            return 0;
+        }
+
+        bytes.clear();
+        bytes.extend(chunk[..read as usize].iter().map(|x| *x as u8));
+
+        if let Err(e) = writer.write_all(&bytes) {
+            copy_io_error(env, "Failed to write copy data", e);
+            return 0;
+        }
+    }
+
+    match writer.finish() {
+        Ok(rows) => rows as jlong,
+        Err(e) => {
+            SqlError::from_postgres("Failed to finish copy", &e).throw(env);
+            0
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::io::BufRead;
+use jni::JNIEnv;
+use jni::objects::{JObject, JString, JValue};
+use jni::sys::jlong;
+use postgres::GenericClient;
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::postgresql_driver::copy_io_error;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     copyOutNative
+ * - Signature:  `(JLjava/lang/String;Ljava/io/OutputStream;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_copyOutNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, output: JObject) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        copy_out(env, &mut *client, stmt, output)
+    })
+}
+
+/// Run a `COPY ... TO STDOUT` statement on a PostgreSQL connection or transaction, writing every chunk of data
+/// to a Java OutputStream as soon as it is received. Returns the number of bytes written.
+/// If writing to the OutputStream fails, the copy is aborted and the Java exception is left pending
+pub fn copy_out<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, output: JObject) -> jlong {
+    let stmt: String = match env.get_string(stmt) {
+        Ok(s) => s.into(),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return 0;
+        }
+    };
+
+    let mut reader = match client.copy_out(&*stmt) {
+        Ok(r) => r,
+        Err(e) => {
+            SqlError::from_postgres("Failed to start copy", &e).throw(env);
+            return 0;
+        }
+    };
+
+    let mut written = 0;
+    loop {
+        let chunk = match reader.fill_buf() {
+            Ok(c) => c,
+            Err(e) => {
+                copy_io_error(env, "Failed to read copy data", e);
+                return 0;
+            }
+        };
+
+        if chunk.is_empty() {
+            break;
+        }
+
+        let len = chunk.len();
+        let java_buf = match env.byte_array_from_slice(chunk) {
+            Ok(b) => b,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to create byte[]: {:?}", e));
+                return 0;
+            }
+        };
+
+        match env.call_method(output, "write", "([BII)V", &[JValue::Object(java_buf.into()), JValue::Int(0), JValue::Int(len as i32)]) {
+            Ok(_) => {},
+            // The IOException thrown by the OutputStream is propagated to the caller
+            Err(jni::errors::Error::JavaException) => return 0,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to write to OutputStream: {:?}", e));
+                return 0;
+            }
+        }
+
+        // Every chunk creates a new local reference, which would otherwise only be freed once the native method returns
+        let _ = env.delete_local_ref(java_buf.into());
+
+        reader.consume(len);
+        written += len as jlong;
+    }
+
+    written
+}
@@ -1,67 +1,75 @@
 use jni::JNIEnv;
 use jni::objects::{JObject, JString};
-use jni::sys::{jlong, jint};
-use postgres::Client;
+use jni::sys::{jlong, jobject};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use postgres::GenericClient;
 use postgres::types::ToSql;
-use crate::jni::common::SqlParameterArray;
-use crate::jni::util::set_error;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::util::{catch_panic, Java};
 
 /**
  * - Class:      PostgreSqlDriver
  * - Method:     executeNative
- * - Signature:  `(JLjava/lang/String;)I`
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)Ldev/array21/jdbd/datatypes/ExecuteResult;`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_executeNative(env: JNIEnv, obj: JObject<'_>, client_ptr: jlong, stmt: JString, params: SqlParameterArray) -> jint {
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_executeNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobject {
+    catch_panic(env, SQL_EXCEPTION, std::ptr::null_mut(), || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return std::ptr::null_mut();
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return std::ptr::null_mut();
+            }
+        };
+
+        execute(env, &mut *client, stmt, params)
+    })
+}
+
+/// Execute a statement on a PostgreSQL connection or transaction
+pub fn execute<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, params: SqlParameterArray) -> jobject {
     let stmt = match env.get_string(stmt) {
         Ok(s) => String::from(s),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert stmt from JString to String: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return std::ptr::null_mut();
         }
     };
 
-    let mut client = unsafe { Box::from_raw(client_ptr as *mut Client)};
-
-    let sql_params_len = match env.get_array_length(params) {
+    let sql_params_rust = match read_sql_parameters(&env, params) {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to get params array length: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &e);
+            return std::ptr::null_mut();
         }
     };
 
-    let sql_params_java = match (0..sql_params_len).into_iter()
-        .map(|x| env.get_object_array_element(params, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to fetch element of sql params array: {:?}", e));
-            return -1;
-        }
-    };
+    let tosql_params=  sql_params_rust.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
 
-    let sql_params_rust = match sql_params_java.into_iter()
-        .map(|x| crate::jni::common::into_sql_parameter(&env, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
+    let affected_rows = match client.execute(&*stmt, tosql_params.as_slice()) {
+        Ok(r) => r,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert sql params element to Rust type: {:?}", e));
-            return -1;
+            SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+            return std::ptr::null_mut();
         }
     };
 
-    let tosql_params=  sql_params_rust.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
-
-    match client.execute(&*stmt, tosql_params.as_slice()) {
-        Ok(_) => {},
+    // PostgreSQL reports neither an insert id nor warnings, use RETURNING for generated values
+    match Java::new_ExecuteResult(env, affected_rows, None, 0, None) {
+        Ok(r) => r.into_inner(),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to execute stmt: {:?}", e));
-            return -1;
+            throw(env, SQL_EXCEPTION, &e);
+            std::ptr::null_mut()
         }
     }
-
-    Box::into_raw(client);
-
-    0
-}
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jlongArray};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::{Java, catch_panic};
+use std::ptr::null_mut;
+use postgres::GenericClient;
+use postgres::types::ToSql;
+use crate::jni::common::{SqlParameterSetArray, into_sql_parameter_sets};
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     executeBatchNative
+ * - Signature:  `(JLjava/lang/String;[[Ldev/array21/jdbd/datatypes/SqlParameter;)[J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_executeBatchNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, param_sets: SqlParameterSetArray) -> jlongArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        let mut transaction = match client.transaction() {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_postgres("Failed to begin transaction", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        // The transaction is rolled back when it is dropped without being committed
+        let counts = execute_batch(env, &mut transaction, stmt, param_sets);
+        if counts.is_null() {
+            return null_mut();
+        }
+
+        if let Err(e) = transaction.commit() {
+            SqlError::from_postgres("Failed to commit batch", &e).throw(env);
+            return null_mut();
+        }
+
+        counts
+    })
+}
+
+/// Prepare a statement once and execute it for every parameter set on a PostgreSQL connection or transaction.
+/// Returns the affected row count per parameter set, or null if an exception was thrown
+pub fn execute_batch<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, param_sets: SqlParameterSetArray) -> jlongArray {
+    let stmt = match env.get_string(stmt) {
+        Ok(s) => String::from(s),
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    let param_sets = match into_sql_parameter_sets(&env, param_sets) {
+        Ok(x) => x,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert sql param sets to Rust types: {}", e));
+            return null_mut();
+        }
+    };
+
+    let prepared = match client.prepare(&stmt) {
+        Ok(p) => p,
+        Err(e) => {
+            SqlError::from_postgres("Failed to prepare stmt", &e).throw(env);
+            return null_mut();
+        }
+    };
+
+    let mut counts = Vec::with_capacity(param_sets.len());
+    for params in param_sets {
+        let tosql_params = params.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
+
+        match client.execute(&prepared, tosql_params.as_slice()) {
+            Ok(count) => counts.push(count as i64),
+            Err(e) => {
+                SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+                return null_mut();
+            }
+        }
+    }
+
+    match Java::new_long_array(env, counts) {
+        Ok(a) => a,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &e);
+            null_mut()
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jlong, jobjectArray};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::postgresql_driver::query::query;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+use std::ptr::null_mut;
+use postgres::GenericClient;
+use crate::jni::common::SqlParameterArray;
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     executeGeneratedKeysNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)[Ldev/array21/jdbd/datatypes/SqlRow;`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_executeGeneratedKeysNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        execute_generated_keys(env, &mut *client, stmt, params)
+    })
+}
+
+/// Execute a statement on a PostgreSQL connection or transaction, returning the rows it inserted or updated.
+/// The RETURNING clause for the key columns has already been appended by the Java side
+pub fn execute_generated_keys<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+    // Read all returned rows at once, without a portal
+    query(env, client, stmt, params, 0)
+}
@@ -1,9 +1,16 @@
 use jni::JNIEnv;
 use jni::objects::JObject;
 use jni::sys::jlong;
-use crate::jni::util::{set_error, get_string_field};
+use std::time::Duration;
+use crate::jni::error::{throw, DRIVER_LOAD_EXCEPTION};
+use crate::jni::util::{get_string_field, get_enum_field, get_integer_field, get_long_field, catch_panic};
+use crate::jni::postgresql_driver::tls::TlsOptions;
 use crate::unwrap_zero;
-use postgres::{Client, NoTls};
+use crate::jni::handle::insert_handle;
+use postgres::Config;
+use postgres::config::SslMode;
+use r2d2_postgres::PostgresConnectionManager;
+use crate::jni::postgresql_driver::PostgresPool;
 
 /**
  * - Class:      PostgreSqlDriver
@@ -12,31 +19,101 @@ use postgres::{Client, NoTls};
  */
 #[no_mangle]
 pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_initializeNative(env: JNIEnv, obj: JObject<'_>) -> jlong {
-    let host = unwrap_zero!(get_string_field(env, obj, "host"));
-    let database = unwrap_zero!(get_string_field(env, obj, "database"));
-    let username = unwrap_zero!(get_string_field(env, obj, "username"));
-    let password = unwrap_zero!(get_string_field(env, obj, "password"));
-
-    let mut config = Client::configure();
-    config.host(&host.unwrap());
-    config.dbname(&database.unwrap());
-
-    if let Some(username) = username {
-        config.user(&username);
-    }
-
-    if let Some(password) = password {
-        config.password(&password);
-    }
-
-    let client = match config.connect(NoTls) {
-        Ok(c) => c,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to create PostgreSQL Client: {:?}", e));
-            return 0;
-        }
-    };
-
-    let rawptr = Box::into_raw(Box::new(client));
-    rawptr as i64
-}
\ No newline at end of file
+    catch_panic(env, DRIVER_LOAD_EXCEPTION, 0, || {
+        let host = unwrap_zero!(get_string_field(env, obj, "host"));
+        let database = unwrap_zero!(get_string_field(env, obj, "database"));
+        let username = unwrap_zero!(get_string_field(env, obj, "username"));
+        let password = unwrap_zero!(get_string_field(env, obj, "password"));
+        let port = unwrap_zero!(get_integer_field(env, obj, "port"));
+        let socket = unwrap_zero!(get_string_field(env, obj, "socket"));
+        let url = unwrap_zero!(get_string_field(env, obj, "url"));
+
+        let mut config = match url {
+            Some(url) => match url.parse::<Config>() {
+                Ok(c) => c,
+                Err(e) => {
+                    throw(env, DRIVER_LOAD_EXCEPTION, &format!("Failed to parse PostgreSQL connection URL: {:?}", e));
+                    return 0;
+                }
+            },
+            None => {
+                let mut config = Config::new();
+                config.ssl_mode(SslMode::Disable);
+                config
+            }
+        };
+
+        let tls_options = TlsOptions {
+            mode: unwrap_zero!(get_enum_field(env, obj, "sslMode", "Ldev/array21/jdbd/drivers/PostgreSqlSslMode;")),
+            root_cert: unwrap_zero!(get_string_field(env, obj, "sslRootCert")),
+            client_cert: unwrap_zero!(get_string_field(env, obj, "sslClientCert")),
+            client_key: unwrap_zero!(get_string_field(env, obj, "sslClientKey")),
+        };
+
+        let (ssl_mode, connector) = match tls_options.build(config.get_ssl_mode()) {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, DRIVER_LOAD_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let min_pool_size = unwrap_zero!(get_integer_field(env, obj, "minPoolSize"));
+        let max_pool_size = unwrap_zero!(get_integer_field(env, obj, "maxPoolSize"));
+        let idle_timeout = unwrap_zero!(get_long_field(env, obj, "idleTimeoutMillis"));
+        let max_lifetime = unwrap_zero!(get_long_field(env, obj, "maxLifetimeMillis"));
+
+        if let Some(host) = host {
+            config.host(&host);
+        }
+
+        // A host starting with a '/' is treated as the directory containing the Unix domain socket
+        if let Some(socket) = socket {
+            config.host(&socket);
+        }
+
+        if let Some(port) = port {
+            config.port(port as u16);
+        }
+
+        if let Some(database) = database {
+            config.dbname(&database);
+        }
+
+        if let Some(username) = username {
+            config.user(&username);
+        }
+
+        if let Some(password) = password {
+            config.password(&password);
+        }
+
+        config.ssl_mode(ssl_mode);
+
+        let mut builder = PostgresPool::builder()
+            .min_idle(min_pool_size.map(|x| x as u32));
+
+        if let Some(max_pool_size) = max_pool_size {
+            builder = builder.max_size(max_pool_size as u32);
+        }
+
+        if let Some(idle_timeout) = idle_timeout {
+            builder = builder.idle_timeout(Some(Duration::from_millis(idle_timeout as u64)));
+        }
+
+        if let Some(max_lifetime) = max_lifetime {
+            builder = builder.max_lifetime(Some(Duration::from_millis(max_lifetime as u64)));
+        }
+
+        let manager = PostgresConnectionManager::new(config, connector);
+        let pool = match builder.build(manager) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, DRIVER_LOAD_EXCEPTION, &format!("Failed to create PostgreSQL Connection Pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        insert_handle(pool)
+    })
+}
\ No newline at end of file
@@ -1,4 +1,32 @@
+use jni::JNIEnv;
+use postgres_native_tls::MakeTlsConnector;
+use r2d2_postgres::PostgresConnectionManager;
+use r2d2::Pool;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION};
+
+mod begin_transaction;
+mod copy_in;
+mod copy_out;
 mod execute;
+mod execute_batch;
+mod generated_keys;
 mod initialize;
 mod query;
-mod unload;
\ No newline at end of file
+mod query_columnar;
+mod query_stream;
+mod result_set;
+mod tls;
+mod transaction;
+pub(crate) mod types;
+mod unload;
+
+/// The connection pool backing a PostgreSqlDriver
+pub type PostgresPool = Pool<PostgresConnectionManager<MakeTlsConnector>>;
+
+/// Throw an io::Error from reading or writing COPY data. Errors reported by the server are thrown as a structured SqlException
+fn copy_io_error(env: JNIEnv, context: &str, e: std::io::Error) {
+    match e.get_ref().and_then(|inner| inner.downcast_ref::<postgres::Error>()) {
+        Some(e) => SqlError::from_postgres(context, e).throw(env),
+        None => throw(env, SQL_EXCEPTION, &format!("{}: {:?}", context, e))
+    }
+}
\ No newline at end of file
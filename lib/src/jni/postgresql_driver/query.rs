@@ -1,140 +1,366 @@
 use jni::JNIEnv;
 use jni::objects::{JObject, JString, JClass};
-use jni::sys::{jlong, jobjectArray};
-use postgres::Client;
-use crate::jni::util::{set_error, Java};
+use jni::sys::{jint, jlong, jobject, jobjectArray};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::{Java, catch_panic, in_local_frame, join_sql_row_arrays, to_sql_row_array};
 use std::ptr::null_mut;
-use postgres::types::ToSql;
-use crate::jni::common::SqlParameterArray;
+use postgres::{GenericClient, Row};
+use postgres::types::{FromSql, ToSql};
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::postgresql_driver::types::{Interval, TimeTz};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
 use crate::unwrap_nullptr;
 
 /**
  * - Class:      PostgreSqlDriver
  * - Method:     queryNative
- * - Signature:  `(JLjava/lang/String;)[Ldev/array21/jdbd/datatypes/SqlRow;`
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;I)[Ldev/array21/jdbd/datatypes/SqlRow;`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_queryNative(env: JNIEnv, obj: JObject<'_>, client_ptr: jlong, stmt: JString, params: SqlParameterArray) -> jobjectArray {
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_queryNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray, fetch_size: jint) -> jobjectArray {
+    catch_panic(env, SQL_EXCEPTION, null_mut(), || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return null_mut();
+            }
+        };
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return null_mut();
+            }
+        };
+
+        query(env, &mut *client, stmt, params, fetch_size)
+    })
+}
+
+/// Run a query on a PostgreSQL connection or transaction.
+/// If `fetch_size` is positive the rows are read through a portal, `fetch_size` rows at a time.
+/// Every batch is converted to SqlRows before the next one is fetched, so only one batch of PostgreSQL rows is held at once.
+/// The returned array still holds the full result, use queryStream to not keep all rows in memory
+pub fn query<C: GenericClient>(env: JNIEnv, client: &mut C, stmt: JString, params: SqlParameterArray, fetch_size: jint) -> jobjectArray {
     let stmt = match env.get_string(stmt) {
         Ok(s) => String::from(s),
         Err(e) => {
-            set_error(env, obj, &format!("Failed to convert stmt from JString to String: {:?}", e));
+            throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
             return null_mut()
         }
     };
-    let mut client = unsafe { Box::from_raw(client_ptr as *mut Client) };
 
-    let sql_params_len = match env.get_array_length(params) {
+    let sql_params_rust = match read_sql_parameters(&env, params) {
         Ok(x) => x,
         Err(e) => {
-            set_error(env, obj, &format!("Failed to get params array length: {:?}", e));
+            throw(env, SQL_EXCEPTION, &e);
             return null_mut();
         }
     };
 
-    let sql_params_java = match (0..sql_params_len).into_iter()
-        .map(|x| env.get_object_array_element(params, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to fetch element of sql params array: {:?}", e));
-            return null_mut();
-        }
-    };
+    let tosql_params=  sql_params_rust.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
 
-    let sql_params_rust = match sql_params_java.into_iter()
-        .map(|x| crate::jni::common::into_sql_parameter(&env, x))
-        .collect::<Result<Vec<_>, jni::errors::Error>>() {
-        Ok(x) => x,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to convert sql params element to Rust type: {:?}", e));
-            return null_mut();
+    if fetch_size > 0 {
+        // Portals only live inside a transaction. On a transaction this is a savepoint
+        let mut transaction = match client.transaction() {
+            Ok(t) => t,
+            Err(e) => {
+                SqlError::from_postgres("Failed to begin transaction", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        let portal = match transaction.bind(&*stmt, tosql_params.as_slice()) {
+            Ok(p) => p,
+            Err(e) => {
+                SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        // dev.array21.jdbd.datatypes.SqlRow[] per batch
+        let mut batches = Vec::new();
+        loop {
+            let result = match transaction.query_portal(&portal, fetch_size) {
+                Ok(r) => r,
+                Err(e) => {
+                    SqlError::from_postgres("Failed to fetch rows", &e).throw(env);
+                    return null_mut();
+                }
+            };
+
+            let exhausted = result.len() < fetch_size as usize;
+
+            let batch = JObject::from(to_sql_row_array(env, &result, to_sql_row));
+            if batch.is_null() {
+                return null_mut();
+            }
+
+            match env.new_global_ref(batch) {
+                Ok(b) => batches.push(b),
+                Err(e) => {
+                    throw(env, SQL_EXCEPTION, &format!("Failed to create global reference: {:?}", e));
+                    return null_mut();
+                }
+            }
+            let _ = env.delete_local_ref(batch);
+
+            if exhausted {
+                break;
+            }
         }
-    };
 
-    let tosql_params=  sql_params_rust.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
-    let result = match client.query(&*stmt, tosql_params.as_slice()) {
-        Ok(r) => r,
-        Err(e) => {
-            set_error(env, obj, &format!("Failed to execute stmt: {:?}", e));
+        drop(portal);
+        if let Err(e) = transaction.commit() {
+            SqlError::from_postgres("Failed to commit transaction", &e).throw(env);
             return null_mut();
         }
-    };
 
-    Box::into_raw(client);
-
-    // Vec of dev.array21.jdbd.datatypes.SqlRow
-    let mut sqlrows = Vec::new();
-
-    for row in result {
-        // Vec of java.lang.Class
-        let mut classes: Vec<JClass> = Vec::new();
-        // Vec of java.lang.Object
-        let mut objects: Vec<JObject> = Vec::new();
-        let mut names: Vec<String> = Vec::new();
-
-        for col in row.columns().iter() {
-            let name = col.name();
-
-            match col.type_().name() {
-                "text" | "varchar" | "bytea" => {
-                    let v: Option<String> = row.get(&name);
-                    classes.push(unwrap_nullptr!(env, obj, Java::String(env)));
-                    names.push(name.to_string());
-                    match v {
-                        Some(v) => {
-                            let string = unwrap_nullptr!(env, obj, Java::new_String(env, v));
-                            objects.push(string.into());
-                        }
-                        None => objects.push(JObject::null())
+        join_sql_row_arrays(env, &batches)
+    } else {
+        let rows = match client.query(&*stmt, tosql_params.as_slice()) {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+                return null_mut();
+            }
+        };
+
+        to_sql_row_array(env, &rows, to_sql_row)
+    }
+}
+
+/// Convert a PostgreSQL row to a dev.array21.jdbd.datatypes.SqlRow. Returns a nullptr if an exception was thrown
+pub fn to_sql_row(env: JNIEnv, row: &Row) -> jobject {
+    // Each value creates a local reference, and each column name another. They are freed with the frame, so they don't pile up over many rows
+    in_local_frame(env, 2 * row.len() as i32 + 8, || new_sql_row(env, row))
+}
+
+fn new_sql_row(env: JNIEnv, row: &Row) -> jobject {
+    // Vec of java.lang.Class
+    let mut classes: Vec<JClass> = Vec::new();
+    // Vec of java.lang.Object
+    let mut objects: Vec<JObject> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+
+    for (index, col) in row.columns().iter().enumerate() {
+        let name = col.name();
+
+        match col.type_().name() {
+            "text" | "varchar" => {
+                let v: Option<String> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
                     }
-                },
-                "int8" | "int2" | "int4" | "numeric" => {
-                    let v: Option<i64> = row.get(&name);
-                    classes.push(unwrap_nullptr!(env, obj, Java::Long(env)));
-                    names.push(name.to_string());
-                    match v {
-                        Some(v) => {
-                            let long = unwrap_nullptr!(env, obj, Java::new_Long(env, v));
-                            objects.push(long);
-                        },
-                        None => objects.push(JObject::null())
+                };
+                classes.push(unwrap_nullptr!(env, Java::String(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let string = unwrap_nullptr!(env, Java::new_String(env, v));
+                        objects.push(string.into());
                     }
-                },
-                "float4" | "float8" => {
-                    let v: Option<f64> = row.get(&*name);
-                    classes.push(unwrap_nullptr!(env, obj, Java::Double(env)));
-                    names.push(name.to_string());
-                    match v {
-                        Some(v) => {
-                            let double = unwrap_nullptr!(env, obj, Java::new_Double(env, v));
-                            objects.push(double);
-                        },
-                        None => objects.push(JObject::null())
+                    None => objects.push(JObject::null())
+                }
+            },
+            "int2" | "int4" | "int8" => {
+                let v: Option<i64> = match get_long(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
                     }
-                },
-                "_bytea" => {
-                    let v: Option<Vec<u8>> = row.get(&*name);
-                    classes.push(unwrap_nullptr!(env, obj, Java::Byte_array(env)));
-                    names.push(name.to_string());
-                    match v {
-                        Some(v) => {
-                            let byte_array = unwrap_nullptr!(env, obj, Java::new_Byte_array_u8(env, v));
-                            objects.push(byte_array);
-                        },
-                        None => objects.push(JObject::null())
+                };
+                classes.push(unwrap_nullptr!(env, Java::Long(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let long = unwrap_nullptr!(env, Java::new_Long(env, v));
+                        objects.push(long);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "float4" | "float8" => {
+                let v: Option<f64> = match get_double(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
                     }
-                },
-                _ => unimplemented!()
+                };
+                classes.push(unwrap_nullptr!(env, Java::Double(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let double = unwrap_nullptr!(env, Java::new_Double(env, v));
+                        objects.push(double);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "bytea" => {
+                let v: Option<Vec<u8>> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::byte_array(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let byte_array = unwrap_nullptr!(env, Java::new_byte_array(env, &v));
+                        objects.push(byte_array);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "date" => {
+                let v: Option<Date> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::LocalDate(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let date = unwrap_nullptr!(env, Java::new_LocalDate(env, v));
+                        objects.push(date);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "timestamp" => {
+                let v: Option<PrimitiveDateTime> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::LocalDateTime(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let date_time = unwrap_nullptr!(env, Java::new_LocalDateTime(env, v));
+                        objects.push(date_time);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "timestamptz" => {
+                let v: Option<OffsetDateTime> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::Instant(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let instant = unwrap_nullptr!(env, Java::new_Instant(env, v));
+                        objects.push(instant);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "time" => {
+                let v: Option<Time> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::LocalTime(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let time = unwrap_nullptr!(env, Java::new_LocalTime(env, v));
+                        objects.push(time);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "timetz" => {
+                let v: Option<TimeTz> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::OffsetTime(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let time = unwrap_nullptr!(env, v.time().and_then(|(time, offset)| Java::new_OffsetTime(env, time, offset)));
+                        objects.push(time);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            "interval" => {
+                let v: Option<Interval> = match get(row, index, name) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        SqlError::column(name, e).throw(env);
+                        return null_mut();
+                    }
+                };
+                classes.push(unwrap_nullptr!(env, Java::Duration(env)));
+                names.push(name.to_string());
+                match v {
+                    Some(v) => {
+                        let duration = unwrap_nullptr!(env, v.duration().and_then(|x| Java::new_Duration(env, x)));
+                        objects.push(duration);
+                    },
+                    None => objects.push(JObject::null())
+                }
+            },
+            other => {
+                SqlError::column(name, format!("Unsupported type '{}' of column '{}'", other, name)).throw(env);
+                return null_mut();
             }
         }
+    }
+
+    // Type = dev.array21.jdbd.datatypes.SqlRow
+    let sqlrow_java = unwrap_nullptr!(env, Java::new_SqlRow(env, names, objects, classes));
+    sqlrow_java.into_inner()
+}
 
-        // Type = dev.array21.jdbd.datatypes.SqlRow
-        let sqlrow_java = unwrap_nullptr!(env, obj, Java::new_SqlRow(env, names, objects, classes));
-        sqlrows.push(sqlrow_java);
+/// Get the value in column `index` of `row`
+fn get<'a, T: FromSql<'a>>(row: &'a Row, index: usize, name: &str) -> Result<Option<T>, String> {
+    row.try_get::<usize, Option<T>>(index)
+        .map_err(|e| format!("Failed to convert value of column '{}': {}", name, e))
+}
+
+/// Get the value of an integer column of any width
+fn get_long(row: &Row, index: usize, name: &str) -> Result<Option<i64>, String> {
+    match row.columns()[index].type_().name() {
+        "int2" => Ok(get::<i16>(row, index, name)?.map(i64::from)),
+        "int4" => Ok(get::<i32>(row, index, name)?.map(i64::from)),
+        _ => get(row, index, name)
     }
+}
 
-    // Convert the Vec of dev.array21.jdbd.datatypes.SqlRow to dev.array21.jdbd.datatypes.SqlRow[]
-    let sqlrow_java_array = unwrap_nullptr!(env, obj, Java::new_SqlRow_array(env, sqlrows));
-    sqlrow_java_array.into_inner()
-}
\ No newline at end of file
+/// Get the value of a float4 or float8 column
+fn get_double(row: &Row, index: usize, name: &str) -> Result<Option<f64>, String> {
+    match row.columns()[index].type_().name() {
+        "float4" => Ok(get::<f32>(row, index, name)?.map(f64::from)),
+        _ => get(row, index, name)
+    }
+}
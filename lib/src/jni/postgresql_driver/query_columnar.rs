@@ -0,0 +1,139 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::jlong;
+use postgres::Row;
+use postgres::fallible_iterator::FallibleIterator;
+use postgres::types::FromSql;
+use std::sync::Mutex;
+use crate::jni::util::catch_panic;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::columnar::{self, Column, ColumnKind};
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     queryColumnarNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_queryColumnarNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let stmt = match env.get_string(stmt) {
+            Ok(s) => String::from(s),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+                return 0;
+            }
+        };
+
+        let sql_params_rust = match read_sql_parameters(&env, params) {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let mut client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        // The statement is prepared first, so the columns are also known if there are no rows
+        let statement = match client.prepare(&stmt) {
+            Ok(s) => s,
+            Err(e) => {
+                SqlError::from_postgres("Failed to prepare stmt", &e).throw(env);
+                return 0;
+            }
+        };
+
+        let mut columns = Vec::new();
+        for col in statement.columns() {
+            let kind = match col.type_().name() {
+                "text" | "varchar" => ColumnKind::String,
+                "int2" | "int4" | "int8" => ColumnKind::Long,
+                "float4" | "float8" => ColumnKind::Double,
+                "bytea" => ColumnKind::Bytes,
+                other => {
+                    throw(env, SQL_EXCEPTION, &format!("Unsupported type '{}' of column '{}'", other, col.name()));
+                    return 0;
+                }
+            };
+
+            columns.push(Column::new(col.name().to_string(), kind));
+        }
+
+        let mut result = match client.query_raw(&statement, sql_params_rust) {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+                return 0;
+            }
+        };
+
+        let mut rows = 0;
+        loop {
+            let row = match result.next() {
+                Ok(Some(r)) => r,
+                Ok(None) => break,
+                Err(e) => {
+                    SqlError::from_postgres("Failed to fetch row", &e).throw(env);
+                    return 0;
+                }
+            };
+
+            for (index, column) in columns.iter_mut().enumerate() {
+                if let Err(e) = push_value(column, &row, index) {
+                    throw(env, SQL_EXCEPTION, &e);
+                    return 0;
+                }
+            }
+
+            rows += 1;
+        }
+
+        match columnar::encode(&columns, rows) {
+            Ok(buf) => insert_handle(Mutex::new(buf)),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                0
+            }
+        }
+    })
+}
+
+/// Push the value in column `index` of `row` to `column`
+fn push_value(column: &mut Column, row: &Row, index: usize) -> Result<(), String> {
+    let name = column.name();
+    match (column.kind(), row.columns()[index].type_().name()) {
+        (ColumnKind::Long, "int2") => column.push_long(get::<i16>(row, index, name)?.map(i64::from)),
+        (ColumnKind::Long, "int4") => column.push_long(get::<i32>(row, index, name)?.map(i64::from)),
+        (ColumnKind::Long, _) => column.push_long(get(row, index, name)?),
+        (ColumnKind::Double, "float4") => column.push_double(get::<f32>(row, index, name)?.map(f64::from)),
+        (ColumnKind::Double, _) => column.push_double(get(row, index, name)?),
+        (ColumnKind::String, _) => column.push_bytes(get::<&str>(row, index, name)?.map(str::as_bytes))?,
+        (ColumnKind::Bytes, _) => column.push_bytes(get::<&[u8]>(row, index, name)?)?,
+    }
+
+    Ok(())
+}
+
+/// Get the value in column `index` of `row`
+fn get<'a, T: FromSql<'a>>(row: &'a Row, index: usize, name: &str) -> Result<Option<T>, String> {
+    row.try_get::<usize, Option<T>>(index)
+        .map_err(|e| format!("Failed to convert value of column '{}': {}", name, e))
+}
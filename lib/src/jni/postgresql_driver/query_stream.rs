@@ -0,0 +1,75 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::{jint, jlong};
+use postgres::types::ToSql;
+use std::sync::Mutex;
+use crate::jni::util::catch_panic;
+use crate::jni::common::{SqlParameterArray, read_sql_parameters};
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::postgresql_driver::result_set::PostgresRows;
+use crate::jni::pinned::Pinned;
+use crate::jni::handle::{get_handle, insert_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+
+/**
+ * - Class:      PostgreSqlDriver
+ * - Method:     queryStreamNative
+ * - Signature:  `(JLjava/lang/String;[Ldev/array21/jdbd/datatypes/SqlParameter;I)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_queryStreamNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, params: SqlParameterArray, fetch_size: jint) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let pool = match get_handle::<PostgresPool>(handle) {
+            Ok(p) => p,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get driver: {}", e));
+                return 0;
+            }
+        };
+
+        let stmt = match env.get_string(stmt) {
+            Ok(s) => String::from(s),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to convert stmt from JString to String: {:?}", e));
+                return 0;
+            }
+        };
+
+        let sql_params_rust = match read_sql_parameters(&env, params) {
+            Ok(x) => x,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &e);
+                return 0;
+            }
+        };
+
+        let client = match pool.get() {
+            Ok(c) => c,
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get PostgreSQL connection from pool: {:?}", e));
+                return 0;
+            }
+        };
+
+        // The result keeps the connection until it is closed, rows are only read from it when they are fetched.
+        // With a fetch size the rows are read through a portal, so the server does not send more rows than requested
+        let result = match Pinned::begin(client, |client| {
+            if fetch_size > 0 {
+                let mut transaction = client.transaction()?;
+                let tosql_params = sql_params_rust.iter().map(|x| x as &(dyn ToSql + Sync)).collect::<Vec<_>>();
+                let portal = transaction.bind(&*stmt, tosql_params.as_slice())?;
+                Ok(PostgresRows::Portal(portal, transaction))
+            } else {
+                client.query_raw(&*stmt, sql_params_rust).map(PostgresRows::Iter)
+            }
+        }) {
+            Ok(r) => r,
+            Err(e) => {
+                SqlError::from_postgres("Failed to execute stmt", &e).throw(env);
+                return 0;
+            }
+        };
+
+        insert_handle(Mutex::new(result))
+    })
+}
@@ -0,0 +1,35 @@
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use crate::jni::postgresql_driver::result_set::{PostgresResultSet, PostgresRows};
+use crate::jni::handle::{get_handle, remove_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      PostgreSqlResultSet
+ * - Method:     closeNative
+ * - Signature:  `(J)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlResultSet_closeNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        let result_set = match get_handle::<PostgresResultSet>(handle) {
+            Ok(r) => r,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get result set: {}", e));
+                return;
+            }
+        };
+
+        let _ = remove_handle::<PostgresResultSet>(handle);
+
+        // The connection returns to the pool once the result has been closed
+        let closed = result_set.lock().unwrap_or_else(|e| e.into_inner()).finish(PostgresRows::close);
+        match closed {
+            Some(Ok(_)) => {},
+            Some(Err(e)) => SqlError::from_postgres("Failed to close result set", &e).throw(env),
+            None => throw(env, INVALID_HANDLE_EXCEPTION, "Result set has already been closed"),
+        }
+    })
+}
@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+use postgres::{Portal, Row, RowIter, Transaction};
+use postgres::fallible_iterator::FallibleIterator;
+use postgres_native_tls::MakeTlsConnector;
+use r2d2::PooledConnection;
+use r2d2_postgres::PostgresConnectionManager;
+use crate::jni::pinned::Pinned;
+
+mod close;
+mod fetch;
+
+/// A streaming query result together with the pooled connection it is read from, backing a PostgreSqlResultSet
+pub type PostgresResultSet = Mutex<Pinned<PooledConnection<PostgresConnectionManager<MakeTlsConnector>>, PostgresRows>>;
+
+/// The rows of a streaming query result
+pub enum PostgresRows {
+    /// Rows are read from the connection as they are fetched
+    Iter(RowIter<'static>),
+    /// Rows are read from a portal, i.e. a server-side cursor, in the transaction it was created in.
+    /// The server only sends the rows which have been requested
+    Portal(Portal, Transaction<'static>),
+}
+
+impl PostgresRows {
+    /// Fetch at most `count` rows. Fewer rows are returned once the result is exhausted
+    pub fn fetch(&mut self, count: usize) -> Result<Vec<Row>, postgres::Error> {
+        match self {
+            Self::Iter(iter) => iter.take(count).collect(),
+            // A portal returns all remaining rows when asked for 0
+            Self::Portal(_, _) if count == 0 => Ok(Vec::new()),
+            Self::Portal(portal, transaction) => transaction.query_portal(portal, count.min(i32::MAX as usize) as i32),
+        }
+    }
+
+    /// Discard the rows which have not been fetched. The transaction of a portal is committed
+    pub fn close(self) -> Result<(), postgres::Error> {
+        match self {
+            Self::Iter(_) => Ok(()),
+            Self::Portal(portal, transaction) => {
+                drop(portal);
+                transaction.commit()
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use native_tls::{Certificate, Identity, TlsConnector};
+use postgres::config::SslMode;
+use postgres_native_tls::MakeTlsConnector;
+
+/// TLS settings as configured on the Java PostgreSqlDriver
+pub struct TlsOptions {
+    /// Name of the PostgreSqlSslMode enum constant
+    pub mode: Option<String>,
+    /// Path to a PEM encoded CA bundle
+    pub root_cert: Option<String>,
+    /// Path to a PEM encoded client certificate
+    pub client_cert: Option<String>,
+    /// Path to a PEM encoded PKCS#8 client key
+    pub client_key: Option<String>,
+}
+
+impl TlsOptions {
+    /// Create the SslMode and TLS connector to configure the PostgreSQL connection with.
+    /// If no mode is configured, `default_mode` is used, e.g. the `sslmode` from a connection URL
+    ///
+    /// The modes follow libpq's semantics:
+    /// - `PREFER` and `REQUIRE` encrypt the connection without verifying the server's certificate,
+    ///   unless a CA bundle is configured, in which case `REQUIRE` behaves like `VERIFY_CA`
+    /// - `VERIFY_CA` verifies the certificate chain, but not the hostname
+    /// - `VERIFY_FULL` verifies both the certificate chain and the hostname
+    pub fn build(&self, default_mode: SslMode) -> Result<(SslMode, MakeTlsConnector), String> {
+        let mode = match (self.mode.as_deref(), default_mode) {
+            (Some(mode), _) => mode,
+            (None, SslMode::Prefer) => "PREFER",
+            (None, SslMode::Require) => "REQUIRE",
+            (None, _) => "DISABLE",
+        };
+
+        let (ssl_mode, verify_chain, verify_hostname) = match mode {
+            "DISABLE" => (SslMode::Disable, false, false),
+            "PREFER" => (SslMode::Prefer, false, false),
+            "REQUIRE" => (SslMode::Require, self.root_cert.is_some(), false),
+            "VERIFY_CA" => (SslMode::Require, true, false),
+            "VERIFY_FULL" => (SslMode::Require, true, true),
+            _ => return Err(format!("Invalid SSL mode '{}'", mode))
+        };
+
+        let mut builder = TlsConnector::builder();
+        builder.danger_accept_invalid_certs(!verify_chain);
+        builder.danger_accept_invalid_hostnames(!verify_hostname);
+
+        if let Some(root_cert) = &self.root_cert {
+            let pem = match std::fs::read(root_cert) {
+                Ok(p) => p,
+                Err(e) => return Err(format!("Failed to read SSL root certificate '{}': {:?}", root_cert, e))
+            };
+
+            let certs = match Certificate::stack_from_pem(&pem) {
+                Ok(c) => c,
+                Err(e) => return Err(format!("Failed to parse SSL root certificate '{}': {:?}", root_cert, e))
+            };
+
+            for cert in certs {
+                builder.add_root_certificate(cert);
+            }
+        }
+
+        match (&self.client_cert, &self.client_key) {
+            (Some(client_cert), Some(client_key)) => {
+                let cert = match std::fs::read(client_cert) {
+                    Ok(c) => c,
+                    Err(e) => return Err(format!("Failed to read SSL client certificate '{}': {:?}", client_cert, e))
+                };
+
+                let key = match std::fs::read(client_key) {
+                    Ok(k) => k,
+                    Err(e) => return Err(format!("Failed to read SSL client key '{}': {:?}", client_key, e))
+                };
+
+                let identity = match Identity::from_pkcs8(&cert, &key) {
+                    Ok(i) => i,
+                    Err(e) => return Err(format!("Failed to create SSL client identity: {:?}", e))
+                };
+
+                builder.identity(identity);
+            },
+            (None, None) => {},
+            _ => return Err("SSL client certificate and SSL client key must be provided together".to_string())
+        }
+
+        let connector = match builder.build() {
+            Ok(c) => c,
+            Err(e) => return Err(format!("Failed to create TLS connector: {:?}", e))
+        };
+
+        Ok((ssl_mode, MakeTlsConnector::new(connector)))
+    }
+}
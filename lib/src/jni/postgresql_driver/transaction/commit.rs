@@ -0,0 +1,35 @@
+use jni::JNIEnv;
+use jni::objects::JObject;
+use jni::sys::jlong;
+use crate::jni::postgresql_driver::transaction::PostgresTransaction;
+use crate::jni::handle::{get_handle, remove_handle};
+use crate::jni::error::{SqlError, throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      PostgreSqlTransaction
+ * - Method:     commitNative
+ * - Signature:  `(J)V`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlTransaction_commitNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, SQL_EXCEPTION, (), || {
+        let transaction = match get_handle::<PostgresTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return;
+            }
+        };
+
+        // The transaction is over after this, whether it succeeds or not
+        let _ = remove_handle::<PostgresTransaction>(handle);
+
+        let result = transaction.lock().unwrap_or_else(|e| e.into_inner()).finish(|t| t.commit());
+        match result {
+            Some(Ok(_)) => {},
+            Some(Err(e)) => SqlError::from_postgres("Failed to commit transaction", &e).throw(env),
+            None => throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back")
+        }
+    })
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use jni::JNIEnv;
+use jni::objects::{JObject, JString};
+use jni::sys::jlong;
+use crate::jni::postgresql_driver::transaction::PostgresTransaction;
+use crate::jni::postgresql_driver::copy_in::copy_in;
+use crate::jni::handle::get_handle;
+use crate::jni::error::{throw, SQL_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
+
+/**
+ * - Class:      PostgreSqlTransaction
+ * - Method:     copyInNative
+ * - Signature:  `(JLjava/lang/String;Ljava/io/InputStream;)J`
+ */
+#[no_mangle]
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlTransaction_copyInNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong, stmt: JString, input: JObject) -> jlong {
+    catch_panic(env, SQL_EXCEPTION, 0, || {
+        let transaction = match get_handle::<PostgresTransaction>(handle) {
+            Ok(t) => t,
+            Err(e) => {
+                throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to get transaction: {}", e));
+                return 0;
+            }
+        };
+        let mut guard = transaction.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = match guard.get() {
+            Some(t) => t,
+            None => {
+                throw(env, INVALID_HANDLE_EXCEPTION, "Transaction has already been committed or rolled back");
+                return 0;
+            }
+        };
+
+        copy_in(env, transaction, stmt, input)
+    })
+}
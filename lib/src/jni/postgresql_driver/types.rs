@@ -0,0 +1,176 @@
+//! The PostgreSQL `timetz` and `interval` types in their binary format.
+//! The other date and time types are converted through the `time` crate, but the `postgres` crate does not support these two
+
+use std::error::Error;
+use postgres::types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+use postgres::types::private::BytesMut;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// A `timetz`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeTz {
+    /// Microseconds since midnight
+    pub micros: i64,
+    /// Offset from UTC in seconds, positive east of Greenwich
+    pub offset: i32,
+}
+
+/// An `interval`. The parts are stored separately, as the length of a month depends on when the interval is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub micros: i64,
+    pub days: i32,
+    pub months: i32,
+}
+
+impl TimeTz {
+    /// Get the time of day and the offset. Fails for 24:00:00, which PostgreSQL allows but java.time does not
+    pub fn time(self) -> Result<(time::Time, time::UtcOffset), String> {
+        let time = match self.micros {
+            0..=86_399_999_999 => time::Time::MIDNIGHT + time::Duration::microseconds(self.micros),
+            _ => return Err(format!("Time of {} microseconds since midnight can't be represented by java.time.OffsetTime", self.micros))
+        };
+
+        match time::UtcOffset::from_whole_seconds(self.offset) {
+            Ok(offset) => Ok((time, offset)),
+            Err(e) => Err(format!("Invalid offset of {} seconds: {}", self.offset, e))
+        }
+    }
+}
+
+impl Interval {
+    /// Create an interval from a duration. The duration is stored as microseconds only, like `interval '90000 seconds'`
+    pub fn from_duration(duration: time::Duration) -> Result<Self, BoxError> {
+        match i64::try_from(duration.whole_microseconds()) {
+            Ok(micros) => Ok(Self { micros, days: 0, months: 0 }),
+            Err(_) => Err(format!("Duration {} is out of range for PostgreSQL", duration).into())
+        }
+    }
+
+    /// Get the length of the interval, counting a day as 24 hours.
+    /// Fails if the interval has months, as their length depends on the date the interval is applied to
+    pub fn duration(self) -> Result<time::Duration, String> {
+        if self.months != 0 {
+            return Err(format!("Interval of {} months, {} days and {} microseconds has months, which can't be represented by java.time.Duration", self.months, self.days, self.micros));
+        }
+
+        Ok(time::Duration::days(self.days as i64) + time::Duration::microseconds(self.micros))
+    }
+}
+
+/// Read the bytes of a big endian integer at an offset
+fn read_be<const N: usize>(buf: &[u8], at: usize) -> Result<[u8; N], BoxError> {
+    buf.get(at..at + N)
+        .and_then(|x| x.try_into().ok())
+        .ok_or_else(|| "Invalid message length".into())
+}
+
+impl<'a> FromSql<'a> for TimeTz {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        if raw.len() != 12 {
+            return Err("Invalid message length".into());
+        }
+
+        // PostgreSQL stores the zone in seconds west of Greenwich
+        Ok(Self {
+            micros: i64::from_be_bytes(read_be(raw, 0)?),
+            offset: -i32::from_be_bytes(read_be(raw, 8)?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TIMETZ
+    }
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        if raw.len() != 16 {
+            return Err("Invalid message length".into());
+        }
+
+        Ok(Self {
+            micros: i64::from_be_bytes(read_be(raw, 0)?),
+            days: i32::from_be_bytes(read_be(raw, 8)?),
+            months: i32::from_be_bytes(read_be(raw, 12)?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        out.extend_from_slice(&self.micros.to_be_bytes());
+        out.extend_from_slice(&self.days.to_be_bytes());
+        out.extend_from_slice(&self.months.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timetz_offset_is_east_of_greenwich() {
+        // 12:30:00+02:00, which PostgreSQL sends with a zone of -7200 seconds
+        let mut raw = (45_000_000_000i64).to_be_bytes().to_vec();
+        raw.extend_from_slice(&(-7200i32).to_be_bytes());
+
+        let time = TimeTz::from_sql(&Type::TIMETZ, &raw).unwrap();
+        assert_eq!(time, TimeTz { micros: 45_000_000_000, offset: 7200 });
+    }
+
+    #[test]
+    fn timetz_time() {
+        let (time, offset) = TimeTz { micros: 45_000_000_001, offset: -3600 }.time().unwrap();
+        assert_eq!(time, time::Time::from_hms_micro(12, 30, 0, 1).unwrap());
+        assert_eq!(offset, time::UtcOffset::from_hms(-1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn timetz_end_of_day() {
+        assert!(TimeTz { micros: 86_400_000_000, offset: 0 }.time().is_err());
+    }
+
+    #[test]
+    fn timetz_invalid_length() {
+        assert!(TimeTz::from_sql(&Type::TIMETZ, &[0; 8]).is_err());
+    }
+
+    #[test]
+    fn interval_round_trip() {
+        let interval = Interval { micros: -1_500_000, days: 3, months: 0 };
+        let mut out = BytesMut::new();
+        interval.to_sql(&Type::INTERVAL, &mut out).unwrap();
+
+        assert_eq!(Interval::from_sql(&Type::INTERVAL, &out).unwrap(), interval);
+    }
+
+    #[test]
+    fn interval_duration() {
+        let interval = Interval { micros: -1_500_000, days: 3, months: 0 };
+        assert_eq!(interval.duration().unwrap(), time::Duration::days(3) - time::Duration::milliseconds(1500));
+    }
+
+    #[test]
+    fn interval_with_months_has_no_duration() {
+        assert!(Interval { micros: 0, days: 0, months: 1 }.duration().is_err());
+    }
+
+    #[test]
+    fn interval_from_duration() {
+        let interval = Interval::from_duration(time::Duration::new(-90_000, -500_000)).unwrap();
+        assert_eq!(interval, Interval { micros: -90_000_000_500, days: 0, months: 0 });
+    }
+}
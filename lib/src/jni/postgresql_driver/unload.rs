@@ -1,7 +1,10 @@
 use jni::JNIEnv;
-use jni::objects::JObject;
 use jni::sys::jlong;
-use postgres::Client;
+use jni::objects::JObject;
+use crate::jni::postgresql_driver::PostgresPool;
+use crate::jni::handle::remove_handle;
+use crate::jni::error::{throw, DRIVER_EXCEPTION, INVALID_HANDLE_EXCEPTION};
+use crate::jni::util::catch_panic;
 
 /**
  * - Class:      PostgreSqlDriver
@@ -9,7 +12,10 @@ use postgres::Client;
  * - Signature:  `(J)V`
  */
 #[no_mangle]
-pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_unloadNative(_env: JNIEnv, _obj: JObject<'_>, ptr: jlong) {
-    let client = unsafe { Box::from_raw(ptr as *mut Client) };
-    drop(client);
-}
\ No newline at end of file
+pub extern "system" fn Java_dev_array21_jdbd_drivers_PostgreSqlDriver_unloadNative(env: JNIEnv, _obj: JObject<'_>, handle: jlong) {
+    catch_panic(env, DRIVER_EXCEPTION, (), || {
+        if let Err(e) = remove_handle::<PostgresPool>(handle) {
+            throw(env, INVALID_HANDLE_EXCEPTION, &format!("Failed to unload driver: {}", e));
+        }
+    })
+}
\ No newline at end of file
@@ -1,47 +1,149 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use jni::JNIEnv;
-use jni::objects::{JObject, JValue, JClass, JString};
+use jni::objects::{GlobalRef, JObject, JValue, JClass, JString};
+use jni::signature::JavaType;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use jni::sys::{jlongArray, jobject, jobjectArray};
+use std::ptr::null_mut;
+use crate::jni::error::{throw, DRIVER_EXCEPTION, SQL_EXCEPTION};
+use crate::jni::cache::cache;
+
+/// Run the body of an exported JNI function, catching any panic.
+/// A panic must never unwind into the JVM, as that aborts the whole process.
+/// If `f` panics, an exception of `class` is thrown with the panic message and `default` is returned
+pub fn catch_panic<T, F: FnOnce() -> T>(env: JNIEnv, class: &str, default: T, f: F) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(v) => v,
+        Err(payload) => {
+            throw(env, class, &format!("Native library panicked: {}", panic_message(&*payload)));
+            default
+        }
+    }
+}
 
-/// Set the errorBuffer field in the provided Object
-pub fn set_error(env: JNIEnv, obj: JObject, e: &str) {
-    eprintln!("{}", e);
-    let jstr = match env.new_string(e.to_string()) {
-        Ok(jstr) => jstr,
+/// Get the message a panic was raised with
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "Unknown panic"
+    }
+}
+
+/// Run `f` in its own local reference frame with room for `capacity` references, so the local references it creates are freed when it returns.
+/// Only the object returned by `f` outlives the frame. `f` returns a nullptr if it threw an exception, which is returned as is
+pub fn in_local_frame<F: FnOnce() -> jobject>(env: JNIEnv, capacity: i32, f: F) -> jobject {
+    match env.with_local_frame(capacity, || Ok(JObject::from(f()))) {
+        Ok(o) => o.into_inner(),
         Err(e) => {
-            eprintln!("Failed to create new JString for error: {:?}", e);
-            panic!("Failed to create new JString for error: {:?}", e)
+            throw(env, SQL_EXCEPTION, &format!("Failed to create local reference frame: {:?}", e));
+            null_mut()
+        }
+    }
+}
+
+/// Convert rows to a dev.array21.jdbd.datatypes.SqlRow[] with `to_sql_row`.
+/// Each row is stored in the array and its local reference deleted as soon as it has been converted,
+/// so the number of local references does not grow with the number of rows. Returns a nullptr if an exception was thrown
+pub fn to_sql_row_array<R>(env: JNIEnv, rows: &[R], to_sql_row: fn(JNIEnv, &R) -> jobject) -> jobjectArray {
+    let array = match env.new_object_array(rows.len() as i32, &cache().SqlRow, JObject::null()) {
+        Ok(a) => a,
+        Err(e) => {
+            throw(env, SQL_EXCEPTION, &format!("Failed to create dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e));
+            return null_mut();
         }
     };
 
-    match env.set_field(obj, "errorBuffer", "Ljava/lang/String;", JValue::Object(jstr.into())) {
-        Ok(_) => {},
+    for (index, row) in rows.iter().enumerate() {
+        let sqlrow_java = JObject::from(to_sql_row(env, row));
+        if sqlrow_java.is_null() {
+            return null_mut();
+        }
+
+        if let Err(e) = env.set_object_array_element(array, index as i32, sqlrow_java) {
+            throw(env, SQL_EXCEPTION, &format!("Failed to set element in dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e));
+            return null_mut();
+        }
+
+        let _ = env.delete_local_ref(sqlrow_java);
+    }
+
+    array
+}
+
+/// Join dev.array21.jdbd.datatypes.SqlRow[] batches into one array, in order.
+/// Each element's local reference is deleted once it has been copied. Returns a nullptr if an exception was thrown
+pub fn join_sql_row_arrays(env: JNIEnv, batches: &[GlobalRef]) -> jobjectArray {
+    let mut lengths = Vec::with_capacity(batches.len());
+    for batch in batches {
+        match env.get_array_length(batch.as_obj().into_inner()) {
+            Ok(l) => lengths.push(l),
+            Err(e) => {
+                throw(env, SQL_EXCEPTION, &format!("Failed to get length of dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e));
+                return null_mut();
+            }
+        }
+    }
+
+    let total = match lengths.iter().try_fold(0i32, |total, l| total.checked_add(*l)) {
+        Some(t) => t,
+        None => {
+            throw(env, SQL_EXCEPTION, "Query result has too many rows for an array, use queryStream");
+            return null_mut();
+        }
+    };
+
+    let array = match env.new_object_array(total, &cache().SqlRow, JObject::null()) {
+        Ok(a) => a,
         Err(e) => {
-            eprintln!("Failed to set errorBuffer field: {:?}", e);
-            panic!("Failed to set errorBuffer field: {:?}", e)
+            throw(env, SQL_EXCEPTION, &format!("Failed to create dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e));
+            return null_mut();
+        }
+    };
+
+    let mut index = 0;
+    for (batch, length) in batches.iter().zip(lengths) {
+        for batch_index in 0..length {
+            let result = env.get_object_array_element(batch.as_obj().into_inner(), batch_index)
+                .and_then(|row| env.set_object_array_element(array, index, row).and_then(|_| env.delete_local_ref(row)));
+
+            if let Err(e) = result {
+                throw(env, SQL_EXCEPTION, &format!("Failed to copy element of dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e));
+                return null_mut();
+            }
+
+            index += 1;
         }
     }
+
+    array
 }
 
-/// Find a class, or if it can not be found set an error and return a nullptr
+/// Find a class, or if it can not be found throw a DriverException and return a nullptr
 #[macro_export]
 macro_rules! find_class {
-    ($env:expr, $obj:expr, $class:expr) => {
+    ($env:expr, $class:expr) => {
         match $env.find_class($class) {
             Ok(c) => c,
             Err(e) => {
-                $crate::jni::set_error($env, $obj, &format!("Unable to find class '{}': {:?}", $class, e));
+                $crate::jni::error::throw($env, $crate::jni::error::DRIVER_EXCEPTION, &format!("Unable to find class '{}': {:?}", $class, e));
                 return ::std::ptr::null_mut();
             }
         }
     }
 }
 
+/// Unwrap, or throw an SqlException and return a nullptr
 #[macro_export]
 macro_rules! unwrap_nullptr {
-    ($env:expr, $obj:expr, $expr:expr) => {
+    ($env:expr, $expr:expr) => {
         match $expr {
             Ok(e) => e,
             Err(e) => {
-                $crate::jni::util::set_error($env, $obj, &e);
+                $crate::jni::error::throw($env, $crate::jni::error::SQL_EXCEPTION, &e);
                 return ::std::ptr::null_mut();
             }
         }
@@ -61,11 +163,11 @@ macro_rules! unwrap_zero {
 
 /// Get the value of a String Field from the provided Object
 pub fn get_string_field(env: JNIEnv, obj: JObject, field_name: &'static str) -> Result<Option<String>, ()> {
-    let field = match env.get_field(obj, &field_name, "Ljava/lang/String;") {
+    let field = match env.get_field(obj, field_name, "Ljava/lang/String;") {
         Ok(f) => f,
         Err(e) => {
             let str_e = format!("Failed to retrieve field '{}': {:?}", field_name, e);
-            set_error(env, obj, &str_e);
+            throw(env, DRIVER_EXCEPTION, &str_e);
             return Err(());
         }
     };
@@ -74,7 +176,7 @@ pub fn get_string_field(env: JNIEnv, obj: JObject, field_name: &'static str) ->
         Ok(o) => o,
         Err(e) => {
             let error = format!("Failed to convert field '{}' from JValue to JObject: {:?}", field_name, e);
-            set_error(env, obj, &error);
+            throw(env, DRIVER_EXCEPTION, &error);
             return Err(());
         }
     };
@@ -87,86 +189,261 @@ pub fn get_string_field(env: JNIEnv, obj: JObject, field_name: &'static str) ->
         Ok(str) => Ok(Some(str.into())),
         Err(e) => {
             let error = format!("Failed to convert field '{}' from JString to String: {:?}", field_name, e);
-            set_error(env, obj, &error);
-            return Err(());
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
         }
     }
 }
 
-pub struct Java();
+/// Get the value of a String[] Field from the provided Object. Null elements are skipped
+pub fn get_string_array_field(env: JNIEnv, obj: JObject, field_name: &'static str) -> Result<Option<Vec<String>>, ()> {
+    let field = match env.get_field(obj, field_name, "[Ljava/lang/String;") {
+        Ok(f) => f,
+        Err(e) => {
+            let str_e = format!("Failed to retrieve field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &str_e);
+            return Err(());
+        }
+    };
 
-impl Java {
-    #![allow(non_snake_case)]   // We permit this so that we can adhere to Java's naming scheme for classes and clearly differentiate between e.g byte (primitive) and Byte (Object)
-    #![allow(unused)]
+    let as_jobject = match field.l() {
+        Ok(o) => o,
+        Err(e) => {
+            let error = format!("Failed to convert field '{}' from JValue to JObject: {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            return Err(());
+        }
+    };
+    if as_jobject.is_null() {
+        return Ok(None);
+    }
 
-    pub fn Byte_array(env: JNIEnv) -> Result<JClass, String> {
-        let Byte_array = match env.new_object_array(0, Self::Byte(env)?, JObject::null()) {
-            Ok(a) => a,
-            Err(e) => return Err(format!("Failed to create new java.lang.Byte[]: {:?}", e))
-        };
+    let len = match env.get_array_length(as_jobject.into_inner()) {
+        Ok(l) => l,
+        Err(e) => {
+            let error = format!("Failed to get length of field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            return Err(());
+        }
+    };
 
-        let class = match env.get_object_class(Byte_array) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Failed to get class from java.lang.Byte[]: {:?}", e))
+    let mut strings = Vec::with_capacity(len as usize);
+    for index in 0..len {
+        let element = match env.get_object_array_element(as_jobject.into_inner(), index) {
+            Ok(e) => JString::from(e),
+            Err(e) => {
+                let error = format!("Failed to get element {} of field '{}': {:?}", index, field_name, e);
+                throw(env, DRIVER_EXCEPTION, &error);
+                return Err(());
+            }
         };
+        if element.is_null() {
+            continue;
+        }
 
-        Ok(class)
+        match env.get_string(element) {
+            Ok(str) => strings.push(str.into()),
+            Err(e) => {
+                let error = format!("Failed to convert element {} of field '{}' from JString to String: {:?}", index, field_name, e);
+                throw(env, DRIVER_EXCEPTION, &error);
+                return Err(());
+            }
+        }
     }
 
-    pub fn String(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/String") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.String: {:?}", e))
+    Ok(Some(strings))
+}
+
+/// Get the value of a boolean Field from the provided Object
+pub fn get_boolean_field(env: JNIEnv, obj: JObject, field_name: &'static str) -> Result<bool, ()> {
+    let field = match env.get_field(obj, field_name, "Z") {
+        Ok(f) => f,
+        Err(e) => {
+            let str_e = format!("Failed to retrieve field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &str_e);
+            return Err(());
         }
-    }
+    };
 
-    pub fn Double(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Double") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Double: {:?}", e))
+    match field.z() {
+        Ok(b) => Ok(b),
+        Err(e) => {
+            let error = format!("Failed to convert field '{}' from JValue to bool: {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
         }
     }
+}
 
-    pub fn Boolean(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Boolean") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Boolean: {:?}", e))
+/// Get the value of a java.lang.Integer Field from the provided Object
+pub fn get_integer_field(env: JNIEnv, obj: JObject, field_name: &'static str) -> Result<Option<i32>, ()> {
+    let boxed = match get_boxed_field(env, obj, field_name, "Ljava/lang/Integer;") {
+        Ok(Some(b)) => b,
+        Ok(None) => return Ok(None),
+        Err(_) => return Err(())
+    };
+
+    match env.call_method(boxed, "intValue", "()I", &[]).and_then(|x| x.i()) {
+        Ok(i) => Ok(Some(i)),
+        Err(e) => {
+            let error = format!("Failed to unbox field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
         }
     }
+}
+
+/// Get the value of a java.lang.Long Field from the provided Object
+pub fn get_long_field(env: JNIEnv, obj: JObject, field_name: &'static str) -> Result<Option<i64>, ()> {
+    let boxed = match get_boxed_field(env, obj, field_name, "Ljava/lang/Long;") {
+        Ok(Some(b)) => b,
+        Ok(None) => return Ok(None),
+        Err(_) => return Err(())
+    };
 
-    pub fn Object(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Object") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Object: {:?}", e))
+    match env.call_method(boxed, "longValue", "()J", &[]).and_then(|x| x.j()) {
+        Ok(j) => Ok(Some(j)),
+        Err(e) => {
+            let error = format!("Failed to unbox field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
         }
     }
+}
 
-    pub fn Byte(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Byte") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Byte: {:?}", e))
+/// Get the value of an Object Field from the provided Object, or `None` if it is null
+fn get_boxed_field<'a>(env: JNIEnv<'a>, obj: JObject<'a>, field_name: &'static str, sig: &'static str) -> Result<Option<JObject<'a>>, ()> {
+    let field = match env.get_field(obj, field_name, sig) {
+        Ok(f) => f,
+        Err(e) => {
+            let str_e = format!("Failed to retrieve field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &str_e);
+            return Err(());
         }
-    }
+    };
 
-    pub fn Long(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Long") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Long: {:?}", e))
+    match field.l() {
+        Ok(o) if o.is_null() => Ok(None),
+        Ok(o) => Ok(Some(o)),
+        Err(e) => {
+            let error = format!("Failed to convert field '{}' from JValue to JObject: {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
         }
     }
+}
+
+/// Get the name of the enum constant stored in an enum Field of the provided Object
+pub fn get_enum_field(env: JNIEnv, obj: JObject, field_name: &'static str, sig: &'static str) -> Result<Option<String>, ()> {
+    let field = match env.get_field(obj, field_name, sig) {
+        Ok(f) => f,
+        Err(e) => {
+            let str_e = format!("Failed to retrieve field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &str_e);
+            return Err(());
+        }
+    };
 
-    pub fn Class(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("java/lang/Class") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class java.lang.Class: {:?}", e))
+    let as_jobject = match field.l() {
+        Ok(o) => o,
+        Err(e) => {
+            let error = format!("Failed to convert field '{}' from JValue to JObject: {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            return Err(());
         }
+    };
+
+    if as_jobject.is_null() {
+        return Ok(None);
     }
 
-    pub fn SqlRow(env: JNIEnv) -> Result<JClass, String> {
-        match env.find_class("dev/array21/jdbd/datatypes/SqlRow") {
-            Ok(c) => Ok(c),
-            Err(e) => Err(format!("Failed to get class dev.array21.jdbd.datatypes.SqlRow: {:?}", e))
+    let name = match env.call_method(as_jobject, "name", "()Ljava/lang/String;", &[]).and_then(|x| x.l()) {
+        Ok(n) => n,
+        Err(e) => {
+            let error = format!("Failed to get the name of enum field '{}': {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            return Err(());
         }
+    };
+
+    match env.get_string(JString::from(name)) {
+        Ok(str) => Ok(Some(str.into())),
+        Err(e) => {
+            let error = format!("Failed to convert the name of enum field '{}' from JString to String: {:?}", field_name, e);
+            throw(env, DRIVER_EXCEPTION, &error);
+            Err(())
+        }
+    }
+}
+
+pub struct Java();
+
+impl Java {
+    #![allow(non_snake_case)]   // We permit this so that we can adhere to Java's naming scheme for classes and clearly differentiate between e.g double (primitive) and Double (Object)
+    #![allow(unused)]
+
+    pub fn byte_array(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().byte_array.as_obj()))
+    }
+
+    pub fn String(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().String.as_obj()))
+    }
+
+    pub fn Double(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Double.as_obj()))
+    }
+
+    pub fn Boolean(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Boolean.as_obj()))
+    }
+
+    pub fn Object(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Object.as_obj()))
+    }
+
+    pub fn Long(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Long.as_obj()))
+    }
+
+    pub fn Integer(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Integer.as_obj()))
+    }
+
+    pub fn Class(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Class.as_obj()))
+    }
+
+    pub fn SqlRow(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().SqlRow.as_obj()))
+    }
+
+    pub fn ExecuteResult(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().ExecuteResult.as_obj()))
+    }
+
+    pub fn LocalDate(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().LocalDate.as_obj()))
+    }
+
+    pub fn LocalDateTime(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().LocalDateTime.as_obj()))
+    }
+
+    pub fn Instant(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Instant.as_obj()))
+    }
+
+    pub fn Duration(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().Duration.as_obj()))
+    }
+
+    pub fn LocalTime(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().LocalTime.as_obj()))
+    }
+
+    pub fn OffsetTime(_env: JNIEnv) -> Result<JClass, String> {
+        Ok(JClass::from(cache().OffsetTime.as_obj()))
     }
 
     pub fn new_String(env: JNIEnv, string: String) -> Result<JString, String> {
@@ -176,53 +453,107 @@ impl Java {
         }
     }
 
-    pub fn new_Long(env: JNIEnv, long: i64) -> Result<JObject<'_>, String> {
-        match env.new_object(Self::Long(env)?, "(J)V", &[JValue::Long(long)]) {
+    pub fn new_Long(env: JNIEnv<'_>, long: i64) -> Result<JObject<'_>, String> {
+        match env.new_object_unchecked(&cache().Long, cache().Long_new.get(), &[JValue::Long(long)]) {
             Ok(long) => Ok(long),
             Err(e) => Err(format!("Failed to convert i64 to java.lang.Long: {:?}", e))
         }
     }
 
-    pub fn new_Byte(env: JNIEnv, byte: u8) -> Result<JObject<'_>, String> {
-        match env.new_object(Self::Byte(env)?, "(B)V", &[JValue::Byte(byte as i8)]) {
-            Ok(byte) => Ok(byte),
-            Err(e) => Err(format!("Failed to convert u8 to java.lang.Byte: {:?}", e))
+    pub fn new_Integer(env: JNIEnv<'_>, integer: i32) -> Result<JObject<'_>, String> {
+        match env.new_object_unchecked(&cache().Integer, cache().Integer_new.get(), &[JValue::Int(integer)]) {
+            Ok(integer) => Ok(integer),
+            Err(e) => Err(format!("Failed to convert i32 to java.lang.Integer: {:?}", e))
         }
     }
 
-    pub fn new_Double(env: JNIEnv, double: f64) -> Result<JObject<'_>, String> {
-        match env.new_object(Self::Double(env)?, "(D)V", &[JValue::Double(double)]) {
-            Ok(double) => Ok(double),
-            Err(e) => Err(format!("Failed to convert f64 to java.lang.Double: {:?}", e))
+    pub fn new_LocalDate(env: JNIEnv<'_>, date: Date) -> Result<JObject<'_>, String> {
+        let args = [JValue::Int(date.year()), JValue::Int(date.month() as i32), JValue::Int(date.day() as i32)];
+        match env.call_static_method_unchecked(JClass::from(cache().LocalDate.as_obj()), cache().LocalDate_of.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(date) => Ok(date),
+            Err(e) => Err(format!("Failed to create java.time.LocalDate: {:?}", e))
         }
     }
 
-    pub fn new_Byte_array_obj<'a>(env: JNIEnv, bytes: Vec<JObject<'a>>) -> Result<JObject<'a>, String> {
-        let array = match env.new_object_array(bytes.len() as i32, Self::Byte(env)?, JObject::null()) {
-            Ok(a) => a,
-            Err(e) => return Err(format!("Failed to create new java.lang.Byte[]: {:?}", e))
-        };
+    pub fn new_LocalDateTime(env: JNIEnv<'_>, date_time: PrimitiveDateTime) -> Result<JObject<'_>, String> {
+        let args = [
+            date_time.year(),
+            date_time.month() as i32,
+            date_time.day() as i32,
+            date_time.hour() as i32,
+            date_time.minute() as i32,
+            date_time.second() as i32,
+            date_time.nanosecond() as i32
+        ].map(JValue::Int);
+        match env.call_static_method_unchecked(JClass::from(cache().LocalDateTime.as_obj()), cache().LocalDateTime_of.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(date_time) => Ok(date_time),
+            Err(e) => Err(format!("Failed to create java.time.LocalDateTime: {:?}", e))
+        }
+    }
 
-        let mut index = 0;
-        for byte in bytes.into_iter() {
-            match env.set_object_array_element(array, index, byte) {
-                Ok(_) => {},
-                Err(e) => return Err(format!("Failed to set object in java.lang.Byte[]: {:?}", e))
-            }
+    pub fn new_Instant(env: JNIEnv<'_>, instant: OffsetDateTime) -> Result<JObject<'_>, String> {
+        let args = [JValue::Long(instant.unix_timestamp()), JValue::Long(instant.nanosecond() as i64)];
+        match env.call_static_method_unchecked(JClass::from(cache().Instant.as_obj()), cache().Instant_ofEpochSecond.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(instant) => Ok(instant),
+            Err(e) => Err(format!("Failed to create java.time.Instant: {:?}", e))
+        }
+    }
 
-            index += 1;
+    pub fn new_Duration(env: JNIEnv<'_>, duration: Duration) -> Result<JObject<'_>, String> {
+        // The nanoseconds have the same sign as the seconds, java.time.Duration normalizes them
+        let args = [JValue::Long(duration.whole_seconds()), JValue::Long(duration.subsec_nanoseconds() as i64)];
+        match env.call_static_method_unchecked(JClass::from(cache().Duration.as_obj()), cache().Duration_ofSeconds.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(duration) => Ok(duration),
+            Err(e) => Err(format!("Failed to create java.time.Duration: {:?}", e))
         }
+    }
 
-        Ok(JObject::from(array))
+    pub fn new_LocalTime(env: JNIEnv<'_>, time: Time) -> Result<JObject<'_>, String> {
+        let args = [JValue::Long((time - Time::MIDNIGHT).whole_nanoseconds() as i64)];
+        match env.call_static_method_unchecked(JClass::from(cache().LocalTime.as_obj()), cache().LocalTime_ofNanoOfDay.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(time) => Ok(time),
+            Err(e) => Err(format!("Failed to create java.time.LocalTime: {:?}", e))
+        }
     }
 
-    pub fn new_Byte_array_u8(env: JNIEnv, bytes: Vec<u8>) -> Result<JObject<'_>, String> {
-        let mut jbytes = Vec::new();
-        for byte in bytes {
-            jbytes.push(Self::new_Byte(env, byte)?)
+    pub fn new_OffsetTime(env: JNIEnv<'_>, time: Time, offset: UtcOffset) -> Result<JObject<'_>, String> {
+        let time = Self::new_LocalTime(env, time)?;
+        let offset = match env.call_static_method_unchecked(JClass::from(cache().ZoneOffset.as_obj()), cache().ZoneOffset_ofTotalSeconds.get(), JavaType::Object(String::new()), &[JValue::Int(offset.whole_seconds())]).and_then(|x| x.l()) {
+            Ok(offset) => offset,
+            Err(e) => return Err(format!("Failed to create java.time.ZoneOffset: {:?}", e))
+        };
+
+        let args = [JValue::Object(time), JValue::Object(offset)];
+        match env.call_static_method_unchecked(JClass::from(cache().OffsetTime.as_obj()), cache().OffsetTime_of.get(), JavaType::Object(String::new()), &args).and_then(|x| x.l()) {
+            Ok(time) => Ok(time),
+            Err(e) => Err(format!("Failed to create java.time.OffsetTime: {:?}", e))
         }
+    }
 
-        Self::new_Byte_array_obj(env, jbytes)
+    pub fn new_Double(env: JNIEnv<'_>, double: f64) -> Result<JObject<'_>, String> {
+        match env.new_object_unchecked(&cache().Double, cache().Double_new.get(), &[JValue::Double(double)]) {
+            Ok(double) => Ok(double),
+            Err(e) => Err(format!("Failed to convert f64 to java.lang.Double: {:?}", e))
+        }
+    }
+
+    pub fn new_byte_array<'a>(env: JNIEnv<'a>, bytes: &[u8]) -> Result<JObject<'a>, String> {
+        match env.byte_array_from_slice(bytes) {
+            Ok(a) => Ok(JObject::from(a)),
+            Err(e) => Err(format!("Failed to create byte[]: {:?}", e))
+        }
+    }
+
+    pub fn new_long_array(env: JNIEnv<'_>, longs: Vec<i64>) -> Result<jlongArray, String> {
+        let array = match env.new_long_array(longs.len() as i32) {
+            Ok(a) => a,
+            Err(e) => return Err(format!("Failed to create long[]: {:?}", e))
+        };
+
+        match env.set_long_array_region(array, 0, &longs) {
+            Ok(_) => Ok(array),
+            Err(e) => Err(format!("Failed to set elements in long[]: {:?}", e))
+        }
     }
 
     pub fn new_String_array_obj<'a>(env: JNIEnv, strings: Vec<JString>) -> Result<JObject<'a>, String> {
@@ -231,20 +562,17 @@ impl Java {
             Err(e) => return Err(format!("Failed to create new java.lang.String[]: {:?}", e))
         };
 
-        let mut index = 0;
-        for string in strings.into_iter() {
-            match env.set_object_array_element(array, index, JObject::from(string)) {
+        for (index, string) in strings.into_iter().enumerate() {
+            match env.set_object_array_element(array, index as i32, JObject::from(string)) {
                 Ok(_) => {},
                 Err(e) => return Err(format!("Failed to set object in java.lang.String[]: {:?}", e))
             }
-
-            index += 1;
         }
 
         Ok(JObject::from(array))
     }
 
-    pub fn new_String_array_string<T: AsRef<str>>(env: JNIEnv, strings: Vec<T>) -> Result<JObject<'_>, String> {
+    pub fn new_String_array_string<T: AsRef<str>>(env: JNIEnv<'_>, strings: Vec<T>) -> Result<JObject<'_>, String> {
         let mut jstrings = Vec::new();
         for string in strings {
             let string = string.as_ref().to_string();
@@ -259,14 +587,11 @@ impl Java {
             Err(e) => return Err(format!("Failed to create new java.lang.Object[]: {:?}", e))
         };
 
-        let mut index = 0;
-        for object in objects.into_iter() {
-            match env.set_object_array_element(array, index, object) {
+        for (index, object) in objects.into_iter().enumerate() {
+            match env.set_object_array_element(array, index as i32, object) {
                 Ok(_) => {},
                 Err(e) => return Err(format!("Failed to set object in java.lang.Object[]: {:?}", e))
             }
-
-            index += 1;
         }
 
         Ok(JObject::from(array))
@@ -278,14 +603,11 @@ impl Java {
             Err(e) => return Err(format!("Failed to create new java.lang.Class[]: {:?}", e))
         };
 
-        let mut index = 0;
-        for class in classes.into_iter() {
-            match env.set_object_array_element(array, index, JObject::from(class)) {
+        for (index, class) in classes.into_iter().enumerate() {
+            match env.set_object_array_element(array, index as i32, JObject::from(class)) {
                 Ok(_) => {},
                 Err(e) => return Err(format!("Failed to set object in java.lang.Class[]: {:?}", e))
             }
-
-            index += 1;
         }
 
         Ok(JObject::from(array))
@@ -296,27 +618,26 @@ impl Java {
         let objects = JValue::Object(Java::new_Object_array(env, objects)?);
         let classes = JValue::Object(Java::new_Class_array(env, classes)?);
 
-        match env.new_object(Self::SqlRow(env)?, "([Ljava/lang/String;[Ljava/lang/Object;[Ljava/lang/Class;)V", &[names, objects, classes]) {
+        match env.new_object_unchecked(&cache().SqlRow, cache().SqlRow_new.get(), &[names, objects, classes]) {
             Ok(r) => Ok(r),
             Err(e) => Err(format!("Failed to create new dev.array21.jdbd.datatypes.SqlRow: {:?}", e))
         }
     }
 
-    pub fn new_SqlRow_array<'a>(env: JNIEnv, rows: Vec<JObject<'a>>) -> Result<JObject<'a>, String> {
-        let array = match env.new_object_array(rows.len() as i32, Self::SqlRow(env)?, JObject::null()) {
-            Ok(r) => r,
-            Err(e) => return Err(format!("Failed to create dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e))
+    pub fn new_ExecuteResult<'a>(env: JNIEnv<'a>, affected_rows: u64, last_insert_id: Option<u64>, warnings: u16, info: Option<String>) -> Result<JObject<'a>, String> {
+        let last_insert_id = match last_insert_id {
+            Some(id) => Java::new_Long(env, id as i64)?,
+            None => JObject::null()
+        };
+        let info = match info {
+            Some(info) => JObject::from(Java::new_String(env, info)?),
+            None => JObject::null()
         };
 
-        let mut index = 0;
-        for row in rows.into_iter() {
-            match env.set_object_array_element(array, index, row) {
-                Ok(_) => {},
-                Err(e) => return Err(format!("Failed to set element in dev.array21.jdbd.datatypes.SqlRow[]: {:?}", e))
-            }
-            index += 1;
+        let args = [JValue::Long(affected_rows as i64), JValue::Object(last_insert_id), JValue::Int(warnings as i32), JValue::Object(info)];
+        match env.new_object_unchecked(&cache().ExecuteResult, cache().ExecuteResult_new.get(), &args) {
+            Ok(r) => Ok(r),
+            Err(e) => Err(format!("Failed to create new dev.array21.jdbd.datatypes.ExecuteResult: {:?}", e))
         }
-
-        Ok(JObject::from(array))
     }
-}
\ No newline at end of file
+}
\ No newline at end of file
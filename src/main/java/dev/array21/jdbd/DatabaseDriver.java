@@ -1,12 +1,33 @@
 package dev.array21.jdbd;
 
+import java.util.List;
+
+import dev.array21.jdbd.datatypes.ColumnarResult;
+import dev.array21.jdbd.datatypes.ExecuteResult;
 import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
 import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.datatypes.TransactionOptions;
 import dev.array21.jdbd.exceptions.SqlException;
 
 public interface DatabaseDriver {
 	
 	public SqlRow[] query(PreparedStatement statement) throws SqlException;
-	public void execute(PreparedStatement statement) throws SqlException;
+	public ResultSet queryStream(PreparedStatement statement) throws SqlException;
+	public ColumnarResult queryColumnar(PreparedStatement statement) throws SqlException;
+	public ExecuteResult execute(PreparedStatement statement) throws SqlException;
+	public SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException;
+	public long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException;
+	public Transaction beginTransaction(TransactionOptions options) throws SqlException;
+	
+	/**
+	 * Begin a transaction with the database's default options
+	 * @return The transaction
+	 * @throws SqlException When beginning the transaction failed
+	 */
+	public default Transaction beginTransaction() throws SqlException {
+		return this.beginTransaction(new TransactionOptions());
+	}
+	
 	public void unload();
 }
@@ -0,0 +1,25 @@
+package dev.array21.jdbd;
+
+import java.util.Iterator;
+
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+
+/**
+ * A query result which is read from the database as it is iterated, rather than all at once.
+ * The result set holds on to a connection until all rows have been read, or until it is closed.
+ * <p>
+ * Errors while iterating are thrown as an {@link dev.array21.jdbd.exceptions.UncheckedSqlException}, use {@link #fetch(int)} to get them as an {@link SqlException} instead
+ */
+public interface ResultSet extends Iterator<SqlRow>, AutoCloseable {
+	
+	public SqlRow[] fetch(int count) throws SqlException;
+	public boolean isClosed();
+	
+	/**
+	 * Close the result set, discarding the rows which have not been read yet. Does nothing if it has already been closed
+	 * @throws SqlException When closing the result set failed
+	 */
+	@Override
+	public void close() throws SqlException;
+}
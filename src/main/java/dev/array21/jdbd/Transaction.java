@@ -0,0 +1,38 @@
+package dev.array21.jdbd;
+
+import java.util.List;
+
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+
+/**
+ * A database transaction. All statements in a transaction run on the same connection.
+ * A transaction which is closed without being committed is rolled back.
+ * <p>
+ * Transactions can be nested with {@link #beginTransaction()}. A nested transaction is backed by a savepoint,
+ * committing it releases the savepoint and rolling it back only undoes the statements run since it began
+ */
+public interface Transaction extends AutoCloseable {
+	
+	public SqlRow[] query(PreparedStatement statement) throws SqlException;
+	public ExecuteResult execute(PreparedStatement statement) throws SqlException;
+	public SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException;
+	public long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException;
+	public void commit() throws SqlException;
+	public void rollback() throws SqlException;
+	public boolean isActive();
+	public Transaction beginTransaction() throws SqlException;
+	public void savepoint(String name) throws SqlException;
+	public void releaseSavepoint(String name) throws SqlException;
+	public void rollbackToSavepoint(String name) throws SqlException;
+	
+	/**
+	 * Roll back the transaction, unless it has already been committed or rolled back
+	 * @throws SqlException When rolling back failed
+	 */
+	@Override
+	public void close() throws SqlException;
+}
@@ -0,0 +1,52 @@
+package dev.array21.jdbd.datatypes;
+
+import java.util.Arrays;
+
+import dev.array21.jdbd.annotations.Nullable;
+
+/**
+ * A column of binary values. The values of all rows are stored back to back in one array,
+ * the value of a row runs from its offset up to the offset of the next row
+ */
+public class BytesColumnVector extends ColumnVector {
+	private final int[] offsets;
+	private final byte[] data;
+	
+	/**
+	 * @param offsets The offset of every row into {@code data}, followed by the end of the last row
+	 */
+	protected BytesColumnVector(String name, byte[] nullBitmap, int[] offsets, byte[] data) {
+		super(name, offsets.length - 1, nullBitmap);
+		if(offsets[offsets.length - 1] != data.length) {
+			throw new IllegalArgumentException("Offsets do not match the length of the data");
+		}
+		
+		this.offsets = offsets;
+		this.data = data;
+	}
+	
+	/**
+	 * @return The offset of every row into {@link #getData()}, followed by the end of the last row
+	 */
+	public int[] getOffsets() {
+		return this.offsets;
+	}
+	
+	public byte[] getData() {
+		return this.data;
+	}
+	
+	/**
+	 * Copy the value of a row
+	 * @param row The index of the row
+	 * @return The value, or null if it is NULL
+	 */
+	@Nullable
+	public byte[] getBytes(int row) {
+		if(this.isNull(row)) {
+			return null;
+		}
+		
+		return Arrays.copyOfRange(this.data, this.offsets[row], this.offsets[row + 1]);
+	}
+}
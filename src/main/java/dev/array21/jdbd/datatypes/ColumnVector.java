@@ -0,0 +1,49 @@
+package dev.array21.jdbd.datatypes;
+
+/**
+ * The values of one column of a {@link ColumnarResult}
+ */
+public abstract class ColumnVector {
+	private final String name;
+	private final int rowCount;
+	private final byte[] nullBitmap;
+	
+	protected ColumnVector(String name, int rowCount, byte[] nullBitmap) {
+		if(rowCount < 0 || nullBitmap.length != (rowCount + 7) / 8) {
+			throw new IllegalArgumentException("Null bitmap does not match the number of rows");
+		}
+		
+		this.name = name;
+		this.rowCount = rowCount;
+		this.nullBitmap = nullBitmap;
+	}
+	
+	public String getName() {
+		return this.name;
+	}
+	
+	public int getRowCount() {
+		return this.rowCount;
+	}
+	
+	/**
+	 * Get the null bitmap. Bit {@code row % 8} of byte {@code row / 8} is set if the value in that row is NULL
+	 * @return The null bitmap
+	 */
+	public byte[] getNullBitmap() {
+		return this.nullBitmap;
+	}
+	
+	/**
+	 * @param row The index of the row
+	 * @return True if the value in the row is NULL
+	 * @throws IndexOutOfBoundsException When the row does not exist
+	 */
+	public boolean isNull(int row) {
+		if(row < 0 || row >= this.rowCount) {
+			throw new IndexOutOfBoundsException(String.format("Row %d out of bounds for %d rows", row, this.rowCount));
+		}
+		
+		return (this.nullBitmap[row / 8] & (1 << (row % 8))) != 0;
+	}
+}
@@ -0,0 +1,178 @@
+package dev.array21.jdbd.datatypes;
+
+import java.nio.BufferUnderflowException;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.nio.charset.StandardCharsets;
+
+import dev.array21.jdbd.annotations.Nullable;
+import dev.array21.jdbd.exceptions.SqlTypeMismatchException;
+
+/**
+ * A query result stored per column, in typed arrays instead of one object per value
+ */
+public class ColumnarResult {
+	private static final byte KIND_LONG = 0;
+	private static final byte KIND_DOUBLE = 1;
+	private static final byte KIND_STRING = 2;
+	private static final byte KIND_BYTES = 3;
+	
+	private final int rowCount;
+	private final ColumnVector[] columns;
+	
+	protected ColumnarResult(int rowCount, ColumnVector[] columns) {
+		for(ColumnVector column : columns) {
+			if(column.getRowCount() != rowCount) {
+				throw new IllegalArgumentException(String.format("Column '%s' has %d rows, expected %d", column.getName(), column.getRowCount(), rowCount));
+			}
+		}
+		
+		this.rowCount = rowCount;
+		this.columns = columns;
+	}
+	
+	public int getRowCount() {
+		return this.rowCount;
+	}
+	
+	public int getColumnCount() {
+		return this.columns.length;
+	}
+	
+	/**
+	 * @param index The index of the column
+	 * @return The column
+	 * @throws IndexOutOfBoundsException When the column does not exist
+	 */
+	public ColumnVector getColumn(int index) {
+		return this.columns[index];
+	}
+	
+	/**
+	 * @param name The name of the column
+	 * @return The column, or null if it does not exist
+	 */
+	@Nullable
+	public ColumnVector getColumn(String name) {
+		for(ColumnVector column : this.columns) {
+			if(column.getName().equals(name)) {
+				return column;
+			}
+		}
+		
+		return null;
+	}
+	
+	@Nullable
+	public LongColumnVector getLongColumn(String name) {
+		return getAndValidate(name, LongColumnVector.class);
+	}
+	
+	@Nullable
+	public DoubleColumnVector getDoubleColumn(String name) {
+		return getAndValidate(name, DoubleColumnVector.class);
+	}
+	
+	@Nullable
+	public StringColumnVector getStringColumn(String name) {
+		return getAndValidate(name, StringColumnVector.class);
+	}
+	
+	/**
+	 * Get a binary column. String columns can be read as binary columns too, their values are encoded as UTF-8
+	 * @param name The name of the column
+	 * @return The column, or null if it does not exist
+	 */
+	@Nullable
+	public BytesColumnVector getBytesColumn(String name) {
+		return getAndValidate(name, BytesColumnVector.class);
+	}
+	
+	@Nullable
+	private <T extends ColumnVector> T getAndValidate(String name, Class<T> clazz) {
+		ColumnVector column = this.getColumn(name);
+		if(column == null) {
+			return null;
+		}
+		
+		if(!clazz.isInstance(column)) {
+			throw new SqlTypeMismatchException(String.format("Column '%s' was requested as %s, but is %s", name, clazz.getSimpleName(), column.getClass().getSimpleName()));
+		}
+		
+		return clazz.cast(column);
+	}
+	
+	/**
+	 * Decode a result encoded by the native library. All numbers are little endian.
+	 * The buffer starts with the number of columns and the number of rows, both as int, followed by every column:
+	 * <ul>
+	 * <li>The length of the column name as int, followed by the name encoded as UTF-8</li>
+	 * <li>The kind of the column as byte: 0 for long, 1 for double, 2 for string and 3 for bytes</li>
+	 * <li>The null bitmap, see {@link ColumnVector#getNullBitmap()}</li>
+	 * <li>For long and double columns, a long or double for every row</li>
+	 * <li>For string and bytes columns, the offsets as int, see {@link BytesColumnVector#getOffsets()}, followed by the data</li>
+	 * </ul>
+	 * @param buffer The encoded result. Its position is not changed
+	 * @return The decoded result
+	 * @throws IllegalArgumentException When the buffer is malformed
+	 */
+	public static ColumnarResult decode(ByteBuffer buffer) {
+		ByteBuffer buf = buffer.duplicate().order(ByteOrder.LITTLE_ENDIAN);
+		
+		try {
+			int columnCount = buf.getInt();
+			int rowCount = buf.getInt();
+			if(columnCount < 0 || rowCount < 0) {
+				throw new IllegalArgumentException("Malformed columnar buffer: negative number of columns or rows");
+			}
+			
+			ColumnVector[] columns = new ColumnVector[columnCount];
+			for(int i = 0; i < columnCount; i++) {
+				String name = new String(getBytes(buf, buf.getInt()), StandardCharsets.UTF_8);
+				byte kind = buf.get();
+				byte[] nullBitmap = getBytes(buf, (rowCount + 7) / 8);
+				
+				switch(kind) {
+				case KIND_LONG:
+					long[] longs = new long[rowCount];
+					buf.asLongBuffer().get(longs);
+					buf.position(buf.position() + rowCount * Long.BYTES);
+					columns[i] = new LongColumnVector(name, nullBitmap, longs);
+					break;
+				case KIND_DOUBLE:
+					double[] doubles = new double[rowCount];
+					buf.asDoubleBuffer().get(doubles);
+					buf.position(buf.position() + rowCount * Double.BYTES);
+					columns[i] = new DoubleColumnVector(name, nullBitmap, doubles);
+					break;
+				case KIND_STRING:
+				case KIND_BYTES:
+					int[] offsets = new int[rowCount + 1];
+					buf.asIntBuffer().get(offsets);
+					buf.position(buf.position() + offsets.length * Integer.BYTES);
+					byte[] data = getBytes(buf, offsets[rowCount]);
+					columns[i] = kind == KIND_STRING
+							? new StringColumnVector(name, nullBitmap, offsets, data)
+							: new BytesColumnVector(name, nullBitmap, offsets, data);
+					break;
+				default:
+					throw new IllegalArgumentException(String.format("Malformed columnar buffer: unknown kind %d of column '%s'", kind, name));
+				}
+			}
+			
+			return new ColumnarResult(rowCount, columns);
+		} catch(BufferUnderflowException e) {
+			throw new IllegalArgumentException("Malformed columnar buffer: truncated", e);
+		}
+	}
+	
+	private static byte[] getBytes(ByteBuffer buf, int length) {
+		if(length < 0) {
+			throw new IllegalArgumentException("Malformed columnar buffer: negative length");
+		}
+		
+		byte[] bytes = new byte[length];
+		buf.get(bytes);
+		return bytes;
+	}
+}
@@ -0,0 +1,21 @@
+package dev.array21.jdbd.datatypes;
+
+/**
+ * A column of floating point numbers. NULL values are stored as 0, use {@link #isNull(int)} to tell them apart
+ */
+public class DoubleColumnVector extends ColumnVector {
+	private final double[] values;
+	
+	protected DoubleColumnVector(String name, byte[] nullBitmap, double[] values) {
+		super(name, values.length, nullBitmap);
+		this.values = values;
+	}
+	
+	public double[] getValues() {
+		return this.values;
+	}
+	
+	public double get(int row) {
+		return this.values[row];
+	}
+}
@@ -0,0 +1,58 @@
+package dev.array21.jdbd.datatypes;
+
+import dev.array21.jdbd.annotations.Nullable;
+
+/**
+ * The result of executing a statement
+ */
+public class ExecuteResult {
+	private final long affectedRows;
+	private final Long lastInsertId;
+	private final int warnings;
+	private final String info;
+	
+	protected ExecuteResult(long affectedRows, Long lastInsertId, int warnings, String info) {
+		this.affectedRows = affectedRows;
+		this.lastInsertId = lastInsertId;
+		this.warnings = warnings;
+		this.info = info;
+	}
+	
+	/**
+	 * @return The number of rows inserted, updated or deleted by the statement
+	 */
+	public long getAffectedRows() {
+		return this.affectedRows;
+	}
+	
+	/**
+	 * Get the value generated for an AUTO_INCREMENT column. Only reported by MySQL
+	 * @return The generated value, or null if the statement did not generate one
+	 */
+	@Nullable
+	public Long getLastInsertId() {
+		return this.lastInsertId;
+	}
+	
+	/**
+	 * Get the number of warnings the statement produced. Only reported by MySQL, always 0 for PostgreSQL
+	 * @return The number of warnings
+	 */
+	public int getWarnings() {
+		return this.warnings;
+	}
+	
+	/**
+	 * Get the information string about the statement, e.g. {@code Rows matched: 1  Changed: 1  Warnings: 0}. Only reported by MySQL
+	 * @return The information string, or null if there is none
+	 */
+	@Nullable
+	public String getInfo() {
+		return this.info;
+	}
+	
+	@Override
+	public String toString() {
+		return String.format("ExecuteResult{affectedRows=%d, lastInsertId=%s, warnings=%d, info=%s}", this.affectedRows, this.lastInsertId, this.warnings, this.info);
+	}
+}
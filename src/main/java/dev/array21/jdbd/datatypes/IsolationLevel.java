@@ -0,0 +1,11 @@
+package dev.array21.jdbd.datatypes;
+
+/**
+ * The isolation level of a transaction
+ */
+public enum IsolationLevel {
+	READ_UNCOMMITTED,
+	READ_COMMITTED,
+	REPEATABLE_READ,
+	SERIALIZABLE
+}
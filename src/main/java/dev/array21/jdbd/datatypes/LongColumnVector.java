@@ -0,0 +1,21 @@
+package dev.array21.jdbd.datatypes;
+
+/**
+ * A column of integers. NULL values are stored as 0, use {@link #isNull(int)} to tell them apart
+ */
+public class LongColumnVector extends ColumnVector {
+	private final long[] values;
+	
+	protected LongColumnVector(String name, byte[] nullBitmap, long[] values) {
+		super(name, values.length, nullBitmap);
+		this.values = values;
+	}
+	
+	public long[] getValues() {
+		return this.values;
+	}
+	
+	public long get(int row) {
+		return this.values[row];
+	}
+}
@@ -0,0 +1,49 @@
+package dev.array21.jdbd.datatypes;
+
+/**
+ * Snapshot of the usage of a driver's connection pool
+ */
+public class PoolState {
+	private final int connections;
+	private final int idleConnections;
+	private final int maxConnections;
+	
+	protected PoolState(int connections, int idleConnections, int maxConnections) {
+		this.connections = connections;
+		this.idleConnections = idleConnections;
+		this.maxConnections = maxConnections;
+	}
+	
+	/**
+	 * @return The number of open connections, both idle and in use
+	 */
+	public int getConnections() {
+		return this.connections;
+	}
+	
+	/**
+	 * @return The number of open connections that are not in use
+	 */
+	public int getIdleConnections() {
+		return this.idleConnections;
+	}
+	
+	/**
+	 * @return The number of open connections that are in use
+	 */
+	public int getActiveConnections() {
+		return this.connections - this.idleConnections;
+	}
+	
+	/**
+	 * @return The maximum number of connections the pool will open
+	 */
+	public int getMaxConnections() {
+		return this.maxConnections;
+	}
+	
+	@Override
+	public String toString() {
+		return String.format("PoolState{connections=%d, idleConnections=%d, maxConnections=%d}", this.connections, this.idleConnections, this.maxConnections);
+	}
+}
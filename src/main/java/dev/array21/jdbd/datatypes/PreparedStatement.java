@@ -1,10 +1,17 @@
 package dev.array21.jdbd.datatypes;
 
 import java.nio.charset.StandardCharsets;
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.OffsetDateTime;
 
 public class PreparedStatement {
 	private String stmt;
 	private SqlParameter[] parameters;
+	private int fetchSize = 0;
 
 	public PreparedStatement(String stmt) {
 		this.stmt = stmt;
@@ -35,6 +42,51 @@ public class PreparedStatement {
 		parameters[pos] = new SqlParameter(val ? 1L : 0L);
 	}
 
+	public void bind(int pos, LocalDate val) {
+		parameters[pos] = new SqlParameter(val);
+	}
+
+	/**
+	 * Bind a date and time without a time zone. When bound to a PostgreSQL timestamptz column, it is taken to be in UTC
+	 * @param pos The position of the parameter
+	 * @param val The value
+	 */
+	public void bind(int pos, LocalDateTime val) {
+		parameters[pos] = new SqlParameter(val);
+	}
+
+	/**
+	 * Bind a point in time, for a MySQL TIMESTAMP or PostgreSQL timestamptz column.
+	 * The value is sent in UTC, so it is stored as the same instant regardless of the time zone of the JVM
+	 * @param pos The position of the parameter
+	 * @param val The value
+	 */
+	public void bind(int pos, Instant val) {
+		parameters[pos] = new SqlParameter(val);
+	}
+
+	/**
+	 * Bind a point in time with an offset. The offset is not stored, the value is bound as the {@link Instant} it represents
+	 * @param pos The position of the parameter
+	 * @param val The value
+	 */
+	public void bind(int pos, OffsetDateTime val) {
+		parameters[pos] = new SqlParameter(val.toInstant());
+	}
+
+	/**
+	 * Bind a duration, for a MySQL TIME or PostgreSQL interval column. TIME ranges from -838:59:59 to 838:59:59
+	 * @param pos The position of the parameter
+	 * @param val The value
+	 */
+	public void bind(int pos, Duration val) {
+		parameters[pos] = new SqlParameter(val);
+	}
+
+	public void bind(int pos, LocalTime val) {
+		parameters[pos] = new SqlParameter(val);
+	}
+
 	public String getStmt() {
 		return this.stmt;
 	}
@@ -43,6 +95,25 @@ public class PreparedStatement {
 		return this.parameters;
 	}
 
+	/**
+	 * Set the number of rows to read from the database at a time.
+	 * For PostgreSQL the rows are then read from a server-side cursor. With {@code queryStream} this keeps memory use bounded for large results,
+	 * while {@code query} still returns every row in one array and only avoids holding the server's rows and their converted copies at the same time
+	 * @param fetchSize The number of rows, 0 to read all rows at once
+	 * @throws IllegalArgumentException When the fetch size is negative
+	 */
+	public void setFetchSize(int fetchSize) {
+		if(fetchSize < 0) {
+			throw new IllegalArgumentException("Fetch size may not be negative");
+		}
+
+		this.fetchSize = fetchSize;
+	}
+
+	public int getFetchSize() {
+		return this.fetchSize;
+	}
+
 	public boolean allBound() {
 		for(SqlParameter x : this.parameters) {
 			if(x == null) {
@@ -52,4 +123,23 @@ public class PreparedStatement {
 
 		return true;
 	}
+
+	/**
+	 * Check if a set of parameters can be used with this statement in a batch: there is a parameter for every placeholder, and none of them are null
+	 * @param parameters The parameters to check
+	 * @return True if the parameters can be used, false otherwise
+	 */
+	public boolean isBoundBy(SqlParameter[] parameters) {
+		if(parameters == null || parameters.length != this.parameters.length) {
+			return false;
+		}
+
+		for(SqlParameter x : parameters) {
+			if(x == null) {
+				return false;
+			}
+		}
+
+		return true;
+	}
 }
@@ -1,5 +1,12 @@
 package dev.array21.jdbd.datatypes;
 
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.ZoneOffset;
+
 @SuppressWarnings("unused") // Accessed from JNI
 public class SqlParameter {
     // All values are read from JNI and should not be renamed
@@ -8,6 +15,8 @@ public class SqlParameter {
     private long longVal;
     private float floatVal;
     private double doubleVal;
+    // Fraction of a second for the temporal types, of which longVal holds the whole seconds
+    private int nanoVal;
 
     public SqlParameter() {
         this.type = SqlParameterType.NULL;
@@ -32,4 +41,31 @@ public class SqlParameter {
         this.doubleVal = doubleVal;
         this.type = SqlParameterType.DOUBLE;
     }
+
+    public SqlParameter(LocalDate date) {
+        this.longVal = date.toEpochDay();
+        this.type = SqlParameterType.DATE;
+    }
+
+    public SqlParameter(LocalDateTime dateTime) {
+        this.longVal = dateTime.toEpochSecond(ZoneOffset.UTC);
+        this.nanoVal = dateTime.getNano();
+        this.type = SqlParameterType.DATETIME;
+    }
+
+    public SqlParameter(Instant instant) {
+        this.longVal = instant.getEpochSecond();
+        this.nanoVal = instant.getNano();
+        this.type = SqlParameterType.TIMESTAMP;
+    }
+
+    public SqlParameter(Duration duration) {
+        this.longVal = duration.getSeconds();
+        this.nanoVal = duration.getNano();
+        this.type = SqlParameterType.TIME;
+    }
+
+    public SqlParameter(LocalTime time) {
+        this(Duration.ofNanos(time.toNanoOfDay()));
+    }
 }
@@ -5,5 +5,9 @@ public enum SqlParameterType {
     BYTES,
     INT,
     FLOAT,
-    DOUBLE
+    DOUBLE,
+    DATE,
+    DATETIME,
+    TIMESTAMP,
+    TIME
 }
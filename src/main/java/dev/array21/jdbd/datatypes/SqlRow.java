@@ -1,5 +1,13 @@
 package dev.array21.jdbd.datatypes;
 
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.OffsetDateTime;
+import java.time.OffsetTime;
+import java.time.ZoneOffset;
 import java.util.HashMap;
 
 import dev.array21.jdbd.annotations.Nullable;
@@ -35,8 +43,97 @@ public class SqlRow {
 	}
 	
 	@Nullable
-	public Byte[] getBytes(String column) {
-		return (Byte[]) getAndValidate(column, Byte[].class);
+	public byte[] getBytes(String column) {
+		return (byte[]) getAndValidate(column, byte[].class);
+	}
+	
+	@Nullable
+	public LocalDate getLocalDate(String column) {
+		return (LocalDate) getAndValidate(column, LocalDate.class);
+	}
+	
+	@Nullable
+	public LocalDateTime getLocalDateTime(String column) {
+		return (LocalDateTime) getAndValidate(column, LocalDateTime.class);
+	}
+	
+	@Nullable
+	public Instant getInstant(String column) {
+		return (Instant) getAndValidate(column, Instant.class);
+	}
+	
+	/**
+	 * Get a point in time, such as a MySQL TIMESTAMP or PostgreSQL timestamptz column, at UTC
+	 * @param column The name of the column
+	 * @return The point in time, or null if the column does not exist or is NULL
+	 */
+	@Nullable
+	public OffsetDateTime getOffsetDateTime(String column) {
+		Instant instant = getInstant(column);
+		return instant == null ? null : instant.atOffset(ZoneOffset.UTC);
+	}
+	
+	/**
+	 * Get a duration, such as a MySQL TIME or PostgreSQL interval column.
+	 * A day of an interval counts as 24 hours. Intervals with months or years have no fixed length, so querying them throws a {@link dev.array21.jdbd.exceptions.SqlException}
+	 * @param column The name of the column
+	 * @return The duration, or null if the column does not exist or is NULL
+	 */
+	@Nullable
+	public Duration getDuration(String column) {
+		return (Duration) getAndValidate(column, Duration.class);
+	}
+	
+	/**
+	 * Get a time of day. MySQL TIME columns are durations, which are only accepted if they are at least zero and less than 24 hours
+	 * @param column The name of the column
+	 * @return The time, or null if the column does not exist or is NULL
+	 * @throws SqlTypeMismatchException When the column is not a time, or a duration outside of a day
+	 */
+	@Nullable
+	public LocalTime getLocalTime(String column) {
+		Pair<Object, Class<?>> pair = columns.get(column);
+		if(pair == null || pair.getB() != Duration.class) {
+			return (LocalTime) getAndValidate(column, LocalTime.class);
+		}
+		
+		Duration duration = (Duration) pair.getA();
+		if(duration == null) {
+			return null;
+		}
+		
+		if(duration.isNegative() || duration.compareTo(Duration.ofDays(1)) >= 0) {
+			throw new SqlTypeMismatchException(String.format("Column '%s' holds %s, which is not a time of day", column, duration.toString()));
+		}
+		
+		return LocalTime.ofNanoOfDay(duration.toNanos());
+	}
+	
+	@Nullable
+	public OffsetTime getOffsetTime(String column) {
+		return (OffsetTime) getAndValidate(column, OffsetTime.class);
+	}
+	
+	/**
+	 * Get a binary column as boxed bytes, as returned by {@link #getBytes(String)} in earlier versions
+	 * @param column The name of the column
+	 * @return The bytes, or null if the column does not exist or is NULL
+	 * @deprecated Use {@link #getBytes(String)} instead, which does not allocate an object per byte
+	 */
+	@Nullable
+	@Deprecated
+	public Byte[] getBoxedBytes(String column) {
+		byte[] bytes = getBytes(column);
+		if(bytes == null) {
+			return null;
+		}
+		
+		Byte[] boxed = new Byte[bytes.length];
+		for(int i = 0; i < bytes.length; i++) {
+			boxed[i] = bytes[i];
+		}
+		
+		return boxed;
 	}
 	
 	@Nullable
@@ -0,0 +1,29 @@
+package dev.array21.jdbd.datatypes;
+
+import java.nio.charset.StandardCharsets;
+
+import dev.array21.jdbd.annotations.Nullable;
+
+/**
+ * A column of strings, stored as UTF-8 like a {@link BytesColumnVector}
+ */
+public class StringColumnVector extends BytesColumnVector {
+	
+	protected StringColumnVector(String name, byte[] nullBitmap, int[] offsets, byte[] data) {
+		super(name, nullBitmap, offsets, data);
+	}
+	
+	/**
+	 * @param row The index of the row
+	 * @return The value, or null if it is NULL
+	 */
+	@Nullable
+	public String getString(int row) {
+		if(this.isNull(row)) {
+			return null;
+		}
+		
+		int[] offsets = this.getOffsets();
+		return new String(this.getData(), offsets[row], offsets[row + 1] - offsets[row], StandardCharsets.UTF_8);
+	}
+}
@@ -0,0 +1,68 @@
+package dev.array21.jdbd.datatypes;
+
+import dev.array21.jdbd.annotations.Nullable;
+
+/**
+ * Options to begin a transaction with
+ */
+public class TransactionOptions {
+	
+	// DO NOT RENAME
+	private IsolationLevel isolationLevel;
+	private boolean readOnly;
+	private boolean deferrable;
+	// END
+	
+	/**
+	 * Set the isolation level of the transaction
+	 * @param isolationLevel The isolation level, or null to use the database's default
+	 * @return The current options
+	 */
+	public TransactionOptions setIsolationLevel(IsolationLevel isolationLevel) {
+		this.isolationLevel = isolationLevel;
+		return this;
+	}
+	
+	/**
+	 * Set whether the transaction may only read data
+	 * @param readOnly True for a read only transaction
+	 * @return The current options
+	 */
+	public TransactionOptions setReadOnly(boolean readOnly) {
+		this.readOnly = readOnly;
+		return this;
+	}
+	
+	/**
+	 * Set whether the transaction is deferrable.
+	 * Only supported by PostgreSQL, where it only has an effect on serializable, read only transactions. Ignored by MySQL
+	 * @param deferrable True for a deferrable transaction
+	 * @return The current options
+	 */
+	public TransactionOptions setDeferrable(boolean deferrable) {
+		this.deferrable = deferrable;
+		return this;
+	}
+	
+	/**
+	 * @return The isolation level, or null if the database's default is used
+	 */
+	@Nullable
+	public IsolationLevel getIsolationLevel() {
+		return this.isolationLevel;
+	}
+	
+	/**
+	 * @return True if the transaction is read only
+	 */
+	public boolean isReadOnly() {
+		return this.readOnly;
+	}
+	
+	/**
+	 * @return True if the transaction is deferrable
+	 */
+	public boolean isDeferrable() {
+		return this.deferrable;
+	}
+}
@@ -0,0 +1,159 @@
+package dev.array21.jdbd.drivers;
+
+import java.util.Arrays;
+import java.util.NoSuchElementException;
+
+import dev.array21.jdbd.ResultSet;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+import dev.array21.jdbd.exceptions.UncheckedSqlException;
+
+/**
+ * A {@link ResultSet} which fetches rows from the native result set in batches.
+ * The native result set is released as soon as its last row has been fetched
+ */
+public abstract class BufferedResultSet implements ResultSet {
+	
+	/**
+	 * The number of rows fetched at a time while iterating, if no fetch size is set
+	 */
+	protected static final int DEFAULT_FETCH_SIZE = 100;
+	
+	private final int fetchSize;
+	private SqlRow[] buffer = new SqlRow[0];
+	private int position = 0;
+	private boolean exhausted = false;
+	private boolean closed = false;
+	
+	/**
+	 * @param fetchSize The number of rows fetched at a time while iterating. If 0, {@link #DEFAULT_FETCH_SIZE} is used
+	 */
+	protected BufferedResultSet(int fetchSize) {
+		this.fetchSize = fetchSize > 0 ? fetchSize : DEFAULT_FETCH_SIZE;
+	}
+	
+	/**
+	 * Fetch the next rows from the native result set
+	 * @param count The maximum number of rows to fetch
+	 * @return The rows, fewer than requested only if the result has no more rows
+	 * @throws SqlException When fetching the rows failed
+	 */
+	protected abstract SqlRow[] fetchRows(int count) throws SqlException;
+	
+	/**
+	 * Release the native result set. Called at most once
+	 * @throws SqlException When releasing the result set failed
+	 */
+	protected abstract void release() throws SqlException;
+	
+	/**
+	 * Check if the result set can still be used
+	 * @throws IllegalStateException When the result set has already been closed
+	 */
+	private void checkOpen() {
+		if(this.closed) {
+			throw new IllegalStateException("Result set has already been closed");
+		}
+	}
+	
+	/**
+	 * Fetch rows from the native result set, releasing it once the result has no more rows
+	 * @param count The maximum number of rows to fetch
+	 * @return The fetched rows
+	 * @throws SqlException When fetching the rows failed
+	 */
+	private SqlRow[] fetchAndRelease(int count) throws SqlException {
+		if(this.exhausted || count <= 0) {
+			return new SqlRow[0];
+		}
+		
+		SqlRow[] rows = this.fetchRows(count);
+		if(rows.length < count) {
+			this.exhausted = true;
+			this.release();
+		}
+		
+		return rows;
+	}
+	
+	/**
+	 * Fetch the next rows
+	 * @param count The maximum number of rows to fetch
+	 * @return The rows, fewer than requested only if the result has no more rows
+	 * @throws IllegalStateException When the result set has already been closed
+	 * @throws SqlException When fetching the rows failed
+	 */
+	@Override
+	public synchronized SqlRow[] fetch(int count) throws SqlException {
+		checkOpen();
+		
+		int buffered = Math.min(count, this.buffer.length - this.position);
+		SqlRow[] rows = Arrays.copyOfRange(this.buffer, this.position, this.position + Math.max(buffered, 0));
+		this.position += rows.length;
+		if(rows.length == count) {
+			return rows;
+		}
+		
+		SqlRow[] fetched = this.fetchAndRelease(count - rows.length);
+		SqlRow[] result = Arrays.copyOf(rows, rows.length + fetched.length);
+		System.arraycopy(fetched, 0, result, rows.length, fetched.length);
+		return result;
+	}
+	
+	/**
+	 * Check if there are more rows, fetching the next batch if needed
+	 * @throws IllegalStateException When the result set has already been closed
+	 * @throws UncheckedSqlException When fetching the rows failed
+	 */
+	@Override
+	public synchronized boolean hasNext() {
+		checkOpen();
+		
+		if(this.position < this.buffer.length) {
+			return true;
+		}
+		
+		try {
+			this.buffer = this.fetchAndRelease(this.fetchSize);
+		} catch(SqlException e) {
+			throw new UncheckedSqlException(e);
+		}
+		this.position = 0;
+		
+		return this.buffer.length > 0;
+	}
+	
+	/**
+	 * Get the next row
+	 * @throws IllegalStateException When the result set has already been closed
+	 * @throws NoSuchElementException When there are no more rows
+	 * @throws UncheckedSqlException When fetching the rows failed
+	 */
+	@Override
+	public synchronized SqlRow next() {
+		if(!this.hasNext()) {
+			throw new NoSuchElementException("Result set has no more rows");
+		}
+		
+		return this.buffer[this.position++];
+	}
+	
+	@Override
+	public synchronized boolean isClosed() {
+		return this.closed;
+	}
+	
+	@Override
+	public synchronized void close() throws SqlException {
+		if(this.closed) {
+			return;
+		}
+		
+		this.closed = true;
+		this.buffer = new SqlRow[0];
+		if(!this.exhausted) {
+			this.exhausted = true;
+			this.release();
+		}
+	}
+}
@@ -0,0 +1,43 @@
+package dev.array21.jdbd.drivers;
+
+import java.nio.ByteBuffer;
+
+import dev.array21.jdbd.datatypes.ColumnarResult;
+import dev.array21.jdbd.exceptions.SqlException;
+
+/**
+ * A result encoded per column by the native library. The buffer is owned by the native library, and is freed once it has been decoded
+ */
+final class ColumnarBuffer {
+	
+	private ColumnarBuffer() {}
+	
+	/**
+	 * Decode an encoded result and free its buffer
+	 * @param ptr The handle to the encoded result
+	 * @return The decoded result
+	 * @throws SqlException When the buffer could not be accessed
+	 */
+	static ColumnarResult decode(long ptr) throws SqlException {
+		try {
+			return ColumnarResult.decode(getNative(ptr));
+		} finally {
+			freeNative(ptr);
+		}
+	}
+	
+	/**
+	 * Get a direct ByteBuffer over an encoded result. It must not be used after the result has been freed
+	 * @param ptr The handle to the encoded result
+	 * @return The buffer
+	 * @throws SqlException When the buffer could not be accessed
+	 */
+	private static native ByteBuffer getNative(long ptr) throws SqlException;
+	
+	/**
+	 * Free an encoded result. This invalidates its handle
+	 * @param ptr The handle to the encoded result
+	 * @throws SqlException When the result could not be freed
+	 */
+	private static native void freeNative(long ptr) throws SqlException;
+}
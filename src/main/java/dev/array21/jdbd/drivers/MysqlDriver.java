@@ -1,14 +1,25 @@
 package dev.array21.jdbd.drivers;
 
 import java.io.IOException;
-import java.sql.SQLException;
+import java.io.InputStream;
 import java.util.Arrays;
+import java.util.List;
+import java.util.concurrent.locks.ReadWriteLock;
+import java.util.concurrent.locks.ReentrantReadWriteLock;
 
 import dev.array21.jdbd.DatabaseDriver;
+import dev.array21.jdbd.ResultSet;
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ColumnarResult;
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PoolState;
 import dev.array21.jdbd.datatypes.PreparedStatement;
 import dev.array21.jdbd.datatypes.SqlParameter;
 import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.datatypes.TransactionOptions;
+import dev.array21.jdbd.exceptions.DriverLoadException;
 import dev.array21.jdbd.exceptions.DriverUnloadedException;
+import dev.array21.jdbd.exceptions.InvalidHandleException;
 import dev.array21.jdbd.exceptions.SqlException;
 import dev.array21.jdbd.exceptions.UnboundPreparedStatementException;
 import dev.array21.jdbd.exceptions.UnsupportedOperatingSystemException;
@@ -16,28 +27,48 @@ import dev.array21.jdbd.exceptions.UnsupportedOperatingSystemException;
 public class MysqlDriver implements DatabaseDriver {
 
 	// DO NOT RENAME
-	private String errorBuffer;
 	private String host;
+	private Integer port;
+	private String socket;
+	private String url;
 	private String username;
 	private String password;
 	private String database;
+	private boolean sslEnabled;
+	private String sslCaFile;
+	private String sslClientIdentity;
+	private String sslClientIdentityPassword;
+	private boolean sslSkipDomainValidation;
+	private String[] localInfilePaths;
+	private Integer minPoolSize;
+	private Integer maxPoolSize;
+	private Long idleTimeoutMillis;
+	private Long maxLifetimeMillis;
+	private Long acquireTimeoutMillis;
+	private boolean resetConnections;
 	// END
 	
 	/**
-	 * Pointer to heap memory where the MySQL connection pool lives.
+	 * Handle to the MySQL connection pool in the native handle registry.
 	 * 
 	 * <h2> SAFETY </h2>
 	 * This value should <strong>NEVER</strong> be altered, except by:
 	 * <ul>
-	 * 	<li> {@link MysqlDriver#MysqlDriver(String, String, String, String) }
+	 * 	<li> {@link MysqlDriver#MysqlDriver(String, Integer, String, String, String, String, String, String, boolean, String, String, String, boolean, String[], Integer, Integer, Long, Long, Long, boolean) }
 	 * 	<li> {@link MysqlDriver#unload() }
 	 * </ul>
 	 * 
-	 * Otherwhise altering this variable is <strong>guaranteed</strong> to result in Undefined Behaviour, and likely a segmentation fault
+	 * Otherwise calls to the native library will fail with an invalid handle error
 	 */
 	private long ptr;
 	private volatile boolean ptrValid = false;
 	
+	/**
+	 * Guards {@link #ptr} against being freed while a statement is using it.
+	 * Statements take the read lock, so they can run concurrently on the connection pool, {@link #unload()} takes the write lock
+	 */
+	private final ReadWriteLock ptrLock = new ReentrantReadWriteLock();
+	
 	private MysqlDriver() {}
 	
 	/**
@@ -45,16 +76,46 @@ public class MysqlDriver implements DatabaseDriver {
 	 * 
 	 * <h2> Thread Safety </h2>
 	 * It is up to the implementer to guarantee that this method is <strong>NOT</strong> called from two threads simultaneously, this can result in Undefined Behaviour
-	 * @param host The MySQL host
+	 * @param host The MySQL host, or null
+	 * @param port The MySQL port, or null for the default
+	 * @param socket The path to the MySQL Unix domain socket, or null
+	 * @param url The MySQL connection URL, or null
 	 * @param username The MySQL username
 	 * @param password The MySQL password
 	 * @param database The MySQL database
+	 * @param sslEnabled Whether to connect using TLS
+	 * @param sslCaFile Path to the PEM or DER encoded CA certificate used to verify the server, or null
+	 * @param sslClientIdentity Path to the PKCS#12 archive holding the client certificate and key, or null
+	 * @param sslClientIdentityPassword Password of the PKCS#12 archive, or null
+	 * @param sslSkipDomainValidation Whether to skip validating the server's hostname against its certificate
+	 * @param localInfilePaths The paths {@code LOAD DATA LOCAL INFILE} may read files from, or null to disable local infile
+	 * @param minPoolSize The minimum number of idle connections in the pool, or null for the default
+	 * @param maxPoolSize The maximum number of connections in the pool, or null for the default
+	 * @param idleTimeoutMillis The time after which an idle connection is closed, or null for the default
+	 * @param maxLifetimeMillis The time after which a connection is closed, or null for the default
+	 * @param acquireTimeoutMillis The maximum time to wait for a connection from the pool, or null for the default
+	 * @param resetConnections Whether to reset the session state of a connection when it is taken from the pool
 	 */
-	protected MysqlDriver(String host, String username, String password, String database) {
+	protected MysqlDriver(String host, Integer port, String socket, String url, String username, String password, String database, boolean sslEnabled, String sslCaFile, String sslClientIdentity, String sslClientIdentityPassword, boolean sslSkipDomainValidation, String[] localInfilePaths, Integer minPoolSize, Integer maxPoolSize, Long idleTimeoutMillis, Long maxLifetimeMillis, Long acquireTimeoutMillis, boolean resetConnections) {
 		this.host = host;
+		this.port = port;
+		this.socket = socket;
+		this.url = url;
 		this.username = username;		
 		this.password = password;
 		this.database = database;
+		this.sslEnabled = sslEnabled;
+		this.sslCaFile = sslCaFile;
+		this.sslClientIdentity = sslClientIdentity;
+		this.sslClientIdentityPassword = sslClientIdentityPassword;
+		this.sslSkipDomainValidation = sslSkipDomainValidation;
+		this.localInfilePaths = localInfilePaths;
+		this.minPoolSize = minPoolSize;
+		this.maxPoolSize = maxPoolSize;
+		this.idleTimeoutMillis = idleTimeoutMillis;
+		this.maxLifetimeMillis = maxLifetimeMillis;
+		this.acquireTimeoutMillis = acquireTimeoutMillis;
+		this.resetConnections = resetConnections;
 	}
 	
 	/**
@@ -62,16 +123,12 @@ public class MysqlDriver implements DatabaseDriver {
 	 * @throws IOException When saving the native library failed
 	 * @throws UnsatisfiedLinkError When loading the native library failed
 	 * @throws UnsupportedOperatingSystemException When the current operating system is unsupported
-	 * @throws RuntimeException When {@link MysqlDriver#initializeNative()} returns an error
+	 * @throws DriverLoadException When the driver could not be initialized, e.g. because the configuration is invalid
 	 */
 	public void loadDriver() throws IOException {
 		DriverManager.loadLibrary();
 		
 		this.ptr = initializeNative();
-		if(this.ptr == 0) {
-			throw new RuntimeException("Failed to load driver: " + this.errorBuffer);
-		}
-		
 		this.ptrValid = true;
 	}
 	
@@ -102,94 +159,338 @@ public class MysqlDriver implements DatabaseDriver {
 		return DriverManager.isLoaded() && this.ptrValid;
 	}
 	
-	/** Query the MySQL database
+	/** Query the MySQL database. This method may be called from multiple threads at the same time, each query uses its own connection from the pool
 	 * @param statement The statement to query with
 	 * @throws IllegalStateException When the native library is not loaded
 	 * @throws DriverUnloadedException When {@link #unload()} has already been called
 	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
-	 * @throws SQLException
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
 	 */
 	@Override
-	public synchronized SqlRow[] query(PreparedStatement statement) throws SqlException {
-		checkValid();
-		
-		if(!statement.allBound()) {
-			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+	public SqlRow[] query(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+			
+			return this.queryNative(this.ptr, statement.getStmt(), statement.getParameters());
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
-		
-		SqlRow[] resultSet = this.queryNative(this.ptr, statement.getStmt(), statement.getParameters());
-		if(resultSet == null) {
-			String buffer = this.errorBuffer;
-			this.errorBuffer = "";
+	}
+	
+	/**
+	 * Query the MySQL database, reading the rows as the result set is iterated.
+	 * The result set holds on to a connection from the pool until all rows have been read, or until it is closed
+	 * @param statement The statement to query with
+	 * @return The result set
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public ResultSet queryStream(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
 			
-			throw new SqlException(buffer);
+			return new MysqlResultSet(this.queryStreamNative(this.ptr, statement.getStmt(), statement.getParameters()), statement.getFetchSize());
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Query the MySQL database, returning the result per column. This is faster than {@link #query(PreparedStatement)} for large results,
+	 * as no object is created per row or value
+	 * @param statement The statement to query with
+	 * @return The result
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public ColumnarResult queryColumnar(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+			
+			return ColumnarBuffer.decode(this.queryColumnarNative(this.ptr, statement.getStmt(), statement.getParameters()));
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
-		
-		return resultSet;
 	}
 
 	/**
 	 * Execute a {@link PreparedStatement}
 	 * @param statement The statemenent to execute
+	 * @return The number of affected rows and, for MySQL, the last insert id and warnings
 	 * @throws IllegalStateException When the native library is not loaded
 	 * @throws DriverUnloadedException When {@link #unload()} has already been called
 	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
 	 */
 	@Override
-	public synchronized void execute(PreparedStatement statement) throws SqlException {
-		checkValid();
-		
-		if(!statement.allBound()) {
-			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+	public ExecuteResult execute(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+
+			return this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} and return the AUTO_INCREMENT values it generated, in a column named {@code GENERATED_KEY}.
+	 * For multi-row inserts the values after the first are derived from the number of inserted rows, which is only correct for plain INSERT statements
+	 * @param statement The statemenent to execute
+	 * @param keyColumns Ignored, as MySQL only reports the AUTO_INCREMENT value
+	 * @return A row for every generated value
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+
+			return this.executeGeneratedKeysNative(this.ptr, statement.getStmt(), statement.getParameters());
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} once for every set of parameters. The statement is prepared once, and all sets are executed
+	 * on one connection in a single transaction: if one of them fails, none of them are applied. Parameters bound on the statement itself are ignored
+	 * @param statement The statemenent to execute
+	 * @param parameterSets The parameters to execute the statement with, one array per execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When a parameter set does not bind every parameter in the statement
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!parameterSets.stream().allMatch(statement::isBoundBy)) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound in every parameter set");
+			}
 
-		int status = this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
-		if(status != 0) {
-			String buffer = this.errorBuffer;
-			this.errorBuffer = "";
+			return this.executeBatchNative(this.ptr, statement.getStmt(), parameterSets.toArray(new SqlParameter[0][]));
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Run a {@code LOAD DATA LOCAL INFILE} statement, sending the data from the stream instead of a file.
+	 * The file name in the statement is ignored. This works regardless of the paths set with {@link MysqlDriverFactory#setLocalInfilePaths(String...)}.
+	 * The data is loaded in a transaction of its own, so nothing is loaded if reading from the stream fails
+	 * @param loadStatement The statement, e.g. {@code LOAD DATA LOCAL INFILE 'stream' INTO TABLE foo FIELDS TERMINATED BY ','}
+	 * @param data The data to load, in the format given in the statement. The stream is not closed
+	 * @return The number of rows loaded, and the warnings reported by the server
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When the statement failed
+	 * @throws IOException When reading from the stream failed. The rows read before the failure are rolled back
+	 */
+	public ExecuteResult loadData(String loadStatement, InputStream data) throws SqlException, IOException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			return this.loadDataNative(this.ptr, loadStatement, data);
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Get the current usage of the connection pool
+	 * @return The state of the connection pool
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When retrieving the state failed
+	 */
+	public PoolState getPoolState() throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			return this.poolStateNative(this.ptr);
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Begin a transaction. The transaction holds on to one connection from the pool until it is committed or rolled back.
+	 * Unloading the driver does not end transactions which are still active
+	 * @param options The options to begin the transaction with
+	 * @return The transaction
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When beginning the transaction failed
+	 */
+	@Override
+	public Transaction beginTransaction(TransactionOptions options) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
 			
-			throw new SqlException(buffer);
+			long transactionPtr = this.beginTransactionNative(this.ptr, options);
+			return new MysqlTransaction(transactionPtr);
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
 	}
 	
 	/**
-	 * Unload the driver
+	 * Unload the driver. Waits for running statements to finish
 	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws InvalidHandleException When the driver's native handle is invalid
 	 * @throws DriverUnloadedException When {@link #unload()} has already been called
 	 */
 	@Override
-	public synchronized void unload() {
-		checkValid();
-		this.ptrValid = false;
-		this.unloadNative(this.ptr);
-		this.ptr = 0;
+	public void unload() {
+		this.ptrLock.writeLock().lock();
+		try {
+			checkValid();
+			this.ptrValid = false;
+			try {
+				this.unloadNative(this.ptr);
+			} finally {
+				this.ptr = 0;
+			}
+		} finally {
+			this.ptrLock.writeLock().unlock();
+		}
 	}
 
 	/**
 	 * Initialize the driver
-	 * @return Returns a heap pointer to where the mysql connection pool is stored
+	 * @return Returns a handle to the mysql connection pool
+	 * @throws DriverLoadException When the driver could not be initialized
 	 */
 	private synchronized native long initializeNative();
 	
 	/**
 	 * Execute a statement
-	 * @param ptr The heap pointer to where the mysql connection pool is stored
+	 * @param ptr The handle to the mysql connection pool
 	 * @param rawStmt The statement to execute, with all params bound
-	 * @return -1 if an error occurred. 0 if everything is OK.
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
 	 */
-	private synchronized native int executeNative(long ptr, String rawStmt, SqlParameter[] parameters);
+	private native ExecuteResult executeNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement and collect the generated AUTO_INCREMENT values
+	 * @param ptr The handle to the mysql connection pool
+	 * @param rawStmt The statement to execute, with all params bound
+	 * @return A row for every generated value
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] executeGeneratedKeysNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement once for every parameter set
+	 * @param ptr The handle to the mysql connection pool
+	 * @param rawStmt The statement to execute
+	 * @param parameterSets The parameters for every execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long[] executeBatchNative(long ptr, String rawStmt, SqlParameter[][] parameterSets) throws SqlException;
+	
+	/**
+	 * Run a LOAD DATA LOCAL INFILE statement with the data from an InputStream
+	 * @param ptr The handle to the mysql connection pool
+	 * @param loadStatement The LOAD DATA LOCAL INFILE statement
+	 * @param data The stream to read the data from
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
+	 * @throws IOException When reading from the stream failed
+	 */
+	private native ExecuteResult loadDataNative(long ptr, String loadStatement, InputStream data) throws SqlException, IOException;
 	
 	/**
 	 * Query the database
-	 * @param ptr The heap pointer to where the mysql connection pool is stored
+	 * @param ptr The handle to the mysql connection pool
+	 * @param rawStmt The statement to query with, with all params bound
+	 * @return The data returned by the database
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] queryNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Query the database without reading the rows
+	 * @param ptr The handle to the mysql connection pool
+	 * @param rawStmt The statement to query with, with all params bound
+	 * @return The handle to the result set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long queryStreamNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Query the database, encoding the result per column
+	 * @param ptr The handle to the mysql connection pool
 	 * @param rawStmt The statement to query with, with all params bound
-	 * @return The data returned by the database, or null if an error occurred
+	 * @return The handle to the encoded result
+	 * @throws SqlException When the statement failed
+	 */
+	private native long queryColumnarNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Begin a transaction
+	 * @param ptr The handle to the mysql connection pool
+	 * @param options The options to begin the transaction with
+	 * @return Returns a handle to the transaction
+	 * @throws SqlException When beginning the transaction failed
+	 */
+	private native long beginTransactionNative(long ptr, TransactionOptions options) throws SqlException;
+	
+	/**
+	 * Get the state of the connection pool
+	 * @param ptr The handle to the mysql connection pool
+	 * @return The state of the connection pool
+	 * @throws SqlException When retrieving the state failed
 	 */
-	private synchronized native SqlRow[] queryNative(long ptr, String rawStmt, SqlParameter[] parameters);
+	private native PoolState poolStateNative(long ptr) throws SqlException;
 	
 	/**
-	 * Unload the driver. This will destory the mysql connection pool and free it's memory
-	 * @param ptr The heap pointer to where the mysql connection pool is stored
+	 * Unload the driver. This will destroy the mysql connection pool and invalidate its handle
+	 * @param ptr The handle to the mysql connection pool
+	 * @throws InvalidHandleException When the handle is invalid
 	 */
-	private synchronized native void unloadNative(long ptr);
+	private native void unloadNative(long ptr);
 }
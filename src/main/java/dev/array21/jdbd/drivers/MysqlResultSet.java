@@ -0,0 +1,48 @@
+package dev.array21.jdbd.drivers;
+
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+
+public class MysqlResultSet extends BufferedResultSet {
+	
+	/**
+	 * Handle to the result set in the native handle registry.
+	 * Becomes invalid once the result set has been released
+	 */
+	private final long ptr;
+	
+	protected MysqlResultSet(long ptr, int fetchSize) {
+		super(fetchSize);
+		this.ptr = ptr;
+	}
+	
+	@Override
+	protected SqlRow[] fetchRows(int count) throws SqlException {
+		if(!DriverManager.isLoaded()) {
+			throw new IllegalStateException("libjdbd is not loaded");
+		}
+		
+		return this.fetchNative(this.ptr, count);
+	}
+	
+	@Override
+	protected void release() throws SqlException {
+		this.closeNative(this.ptr);
+	}
+	
+	/**
+	 * Fetch the next rows of the result set
+	 * @param ptr The handle to the result set
+	 * @param count The maximum number of rows to fetch
+	 * @return The rows, fewer than requested only if the result has no more rows
+	 * @throws SqlException When fetching the rows failed
+	 */
+	private native SqlRow[] fetchNative(long ptr, int count) throws SqlException;
+	
+	/**
+	 * Close the result set and return its connection to the pool. This invalidates its handle
+	 * @param ptr The handle to the result set
+	 * @throws SqlException When closing the result set failed
+	 */
+	private native void closeNative(long ptr) throws SqlException;
+}
@@ -0,0 +1,310 @@
+package dev.array21.jdbd.drivers;
+
+import java.io.IOException;
+import java.io.InputStream;
+import java.util.List;
+
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+import dev.array21.jdbd.exceptions.UnboundPreparedStatementException;
+
+public class MysqlTransaction implements Transaction {
+	
+	/**
+	 * Handle to the transaction in the native handle registry.
+	 * Becomes invalid once the transaction has been committed or rolled back
+	 */
+	private final long ptr;
+	private boolean active = true;
+	
+	protected MysqlTransaction(long ptr) {
+		this.ptr = ptr;
+	}
+	
+	/**
+	 * Check if the transaction can still be used
+	 * @throws IllegalStateException When the native library is not loaded, or the transaction has already been committed or rolled back
+	 */
+	private void checkActive() {
+		if(!DriverManager.isLoaded()) {
+			throw new IllegalStateException("libjdbd is not loaded");
+		}
+		
+		if(!this.active) {
+			throw new IllegalStateException("Transaction has already been committed or rolled back");
+		}
+	}
+	
+	/**
+	 * Check if the transaction is active
+	 * @return True if it has not been committed or rolled back yet, false otherwise
+	 */
+	@Override
+	public synchronized boolean isActive() {
+		return this.active;
+	}
+	
+	/**
+	 * Query the MySQL database within this transaction
+	 * @param statement The statement to query with
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported
+	 */
+	@Override
+	public synchronized SqlRow[] query(PreparedStatement statement) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.queryNative(this.ptr, statement.getStmt(), statement.getParameters());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction
+	 * @param statement The statement to execute
+	 * @return The result of the statement
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized ExecuteResult execute(PreparedStatement statement) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction and return the AUTO_INCREMENT values it generated, in a column named {@code GENERATED_KEY}.
+	 * For multi-row inserts the values after the first are derived from the number of inserted rows, which is only correct for plain INSERT statements
+	 * @param statement The statement to execute
+	 * @param keyColumns Ignored, as MySQL only reports the AUTO_INCREMENT value
+	 * @return A row for every generated value
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.executeGeneratedKeysNative(this.ptr, statement.getStmt(), statement.getParameters());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction once for every set of parameters. The statement is prepared once.
+	 * Parameters bound on the statement itself are ignored
+	 * @param statement The statement to execute
+	 * @param parameterSets The parameters to execute the statement with, one array per execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When a parameter set does not bind every parameter in the statement
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+		checkActive();
+		
+		if(!parameterSets.stream().allMatch(statement::isBoundBy)) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound in every parameter set");
+		}
+		
+		return this.executeBatchNative(this.ptr, statement.getStmt(), parameterSets.toArray(new SqlParameter[0][]));
+	}
+	
+	/**
+	 * Run a {@code LOAD DATA LOCAL INFILE} statement within this transaction, sending the data from the stream instead of a file.
+	 * The file name in the statement is ignored. If reading from the stream fails, the rows read before the failure are rolled back
+	 * to a savepoint named {@code jdbd_load_data}, and the transaction stays active
+	 * @param loadStatement The statement, e.g. {@code LOAD DATA LOCAL INFILE 'stream' INTO TABLE foo FIELDS TERMINATED BY ','}
+	 * @param data The data to load, in the format given in the statement. The stream is not closed
+	 * @return The number of rows loaded, and the warnings reported by the server
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When the statement failed
+	 * @throws IOException When reading from the stream failed. The rows read before the failure are rolled back
+	 */
+	public synchronized ExecuteResult loadData(String loadStatement, InputStream data) throws SqlException, IOException {
+		checkActive();
+		return this.loadDataNative(this.ptr, loadStatement, data);
+	}
+	
+	/**
+	 * Commit the transaction. The transaction can not be used anymore afterwards, even if committing failed
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When committing failed
+	 */
+	@Override
+	public synchronized void commit() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.commitNative(this.ptr);
+	}
+	
+	/**
+	 * Roll back the transaction. The transaction can not be used anymore afterwards, even if rolling back failed
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When rolling back failed
+	 */
+	@Override
+	public synchronized void rollback() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.rollbackNative(this.ptr);
+	}
+	
+	@Override
+	public synchronized void close() throws SqlException {
+		if(this.active) {
+			this.rollback();
+		}
+	}
+	
+	/**
+	 * Begin a transaction nested in this transaction, backed by a savepoint
+	 * @return The nested transaction
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	@Override
+	public synchronized Transaction beginTransaction() throws SqlException {
+		checkActive();
+		return NestedTransaction.begin(this, this);
+	}
+	
+	/**
+	 * Create a savepoint. Creating a savepoint with the name of an existing savepoint replaces it
+	 * @param name The name of the savepoint. May only contain letters, digits and underscores
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	@Override
+	public synchronized void savepoint(String name) throws SqlException {
+		checkActive();
+		this.savepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Release a savepoint, keeping the changes made since it was created
+	 * @param name The name of the savepoint
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When releasing the savepoint failed
+	 */
+	@Override
+	public synchronized void releaseSavepoint(String name) throws SqlException {
+		checkActive();
+		this.releaseSavepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Undo all changes made since a savepoint was created. The savepoint itself is kept
+	 * @param name The name of the savepoint
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When rolling back to the savepoint failed
+	 */
+	@Override
+	public synchronized void rollbackToSavepoint(String name) throws SqlException {
+		checkActive();
+		this.rollbackToSavepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Execute a statement
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute, with all params bound
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
+	 */
+	private native ExecuteResult executeNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement and collect its generated keys
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute, with all params bound
+	 * @return The generated keys
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] executeGeneratedKeysNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement once for every parameter set
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute
+	 * @param parameterSets The parameters for every execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long[] executeBatchNative(long ptr, String rawStmt, SqlParameter[][] parameterSets) throws SqlException;
+	
+	/**
+	 * Run a LOAD DATA LOCAL INFILE statement with the data from an InputStream
+	 * @param ptr The handle to the transaction
+	 * @param loadStatement The LOAD DATA LOCAL INFILE statement
+	 * @param data The stream to read the data from
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
+	 * @throws IOException When reading from the stream failed
+	 */
+	private native ExecuteResult loadDataNative(long ptr, String loadStatement, InputStream data) throws SqlException, IOException;
+	
+	/**
+	 * Query the database
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to query with, with all params bound
+	 * @return The data returned by the database
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] queryNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Commit the transaction. This invalidates its handle
+	 * @param ptr The handle to the transaction
+	 * @throws SqlException When committing failed
+	 */
+	private native void commitNative(long ptr) throws SqlException;
+	
+	/**
+	 * Roll back the transaction. This invalidates its handle
+	 * @param ptr The handle to the transaction
+	 * @throws SqlException When rolling back failed
+	 */
+	private native void rollbackNative(long ptr) throws SqlException;
+	
+	/**
+	 * Create a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	private native void savepointNative(long ptr, String name) throws SqlException;
+	
+	/**
+	 * Release a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When releasing the savepoint failed
+	 */
+	private native void releaseSavepointNative(long ptr, String name) throws SqlException;
+	
+	/**
+	 * Roll back to a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When rolling back to the savepoint failed
+	 */
+	private native void rollbackToSavepointNative(long ptr, String name) throws SqlException;
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+package dev.array21.jdbd.drivers;
+
+import java.util.List;
+import java.util.concurrent.atomic.AtomicLong;
+
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+
+/**
+ * A transaction nested in another transaction, backed by a savepoint on the outermost transaction
+ */
+public class NestedTransaction implements Transaction {
+	
+	private static final AtomicLong SAVEPOINT_COUNTER = new AtomicLong();
+	
+	/**
+	 * The outermost transaction, which all statements run on
+	 */
+	private final Transaction root;
+	private final Transaction parent;
+	private final String savepoint;
+	private boolean active = true;
+	
+	private NestedTransaction(Transaction root, Transaction parent, String savepoint) {
+		this.root = root;
+		this.parent = parent;
+		this.savepoint = savepoint;
+	}
+	
+	/**
+	 * Begin a nested transaction by creating a savepoint on the outermost transaction
+	 * @param root The outermost transaction
+	 * @param parent The transaction to nest in
+	 * @return The nested transaction
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	protected static NestedTransaction begin(Transaction root, Transaction parent) throws SqlException {
+		String savepoint = "jdbd_savepoint_" + SAVEPOINT_COUNTER.incrementAndGet();
+		root.savepoint(savepoint);
+		return new NestedTransaction(root, parent, savepoint);
+	}
+	
+	/**
+	 * Check if the transaction can still be used
+	 * @throws IllegalStateException When this transaction, or a transaction it is nested in, has already been committed or rolled back
+	 */
+	private void checkActive() {
+		if(!this.isActive()) {
+			throw new IllegalStateException("Transaction has already been committed or rolled back");
+		}
+	}
+	
+	/**
+	 * Check if the transaction is active
+	 * @return True if neither this transaction, nor a transaction it is nested in, has been committed or rolled back yet
+	 */
+	@Override
+	public synchronized boolean isActive() {
+		return this.active && this.parent.isActive();
+	}
+	
+	@Override
+	public synchronized SqlRow[] query(PreparedStatement statement) throws SqlException {
+		checkActive();
+		return this.root.query(statement);
+	}
+	
+	@Override
+	public synchronized ExecuteResult execute(PreparedStatement statement) throws SqlException {
+		checkActive();
+		return this.root.execute(statement);
+	}
+	
+	@Override
+	public synchronized SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+		checkActive();
+		return this.root.executeGeneratedKeys(statement, keyColumns);
+	}
+	
+	@Override
+	public synchronized long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+		checkActive();
+		return this.root.executeBatch(statement, parameterSets);
+	}
+	
+	/**
+	 * Commit the nested transaction by releasing its savepoint.
+	 * Its changes only become permanent once the outermost transaction is committed
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When releasing the savepoint failed
+	 */
+	@Override
+	public synchronized void commit() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.root.releaseSavepoint(this.savepoint);
+	}
+	
+	/**
+	 * Roll back the nested transaction by rolling back to its savepoint. The outer transaction stays usable
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When rolling back to the savepoint failed
+	 */
+	@Override
+	public synchronized void rollback() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.root.rollbackToSavepoint(this.savepoint);
+		this.root.releaseSavepoint(this.savepoint);
+	}
+	
+	@Override
+	public synchronized void close() throws SqlException {
+		if(this.isActive()) {
+			this.rollback();
+		}
+	}
+	
+	@Override
+	public synchronized Transaction beginTransaction() throws SqlException {
+		checkActive();
+		return NestedTransaction.begin(this.root, this);
+	}
+	
+	@Override
+	public synchronized void savepoint(String name) throws SqlException {
+		checkActive();
+		this.root.savepoint(name);
+	}
+	
+	@Override
+	public synchronized void releaseSavepoint(String name) throws SqlException {
+		checkActive();
+		this.root.releaseSavepoint(name);
+	}
+	
+	@Override
+	public synchronized void rollbackToSavepoint(String name) throws SqlException {
+		checkActive();
+		this.root.rollbackToSavepoint(name);
+	}
+}
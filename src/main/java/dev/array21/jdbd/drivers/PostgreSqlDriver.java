@@ -1,12 +1,25 @@
 package dev.array21.jdbd.drivers;
 
 import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.util.List;
+import java.util.StringJoiner;
+import java.util.concurrent.locks.ReadWriteLock;
+import java.util.concurrent.locks.ReentrantReadWriteLock;
 
 import dev.array21.jdbd.DatabaseDriver;
+import dev.array21.jdbd.ResultSet;
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ColumnarResult;
+import dev.array21.jdbd.datatypes.ExecuteResult;
 import dev.array21.jdbd.datatypes.PreparedStatement;
 import dev.array21.jdbd.datatypes.SqlParameter;
 import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.datatypes.TransactionOptions;
+import dev.array21.jdbd.exceptions.DriverLoadException;
 import dev.array21.jdbd.exceptions.DriverUnloadedException;
+import dev.array21.jdbd.exceptions.InvalidHandleException;
 import dev.array21.jdbd.exceptions.SqlException;
 import dev.array21.jdbd.exceptions.UnboundPreparedStatementException;
 import dev.array21.jdbd.exceptions.UnsupportedOperatingSystemException;
@@ -14,28 +27,44 @@ import dev.array21.jdbd.exceptions.UnsupportedOperatingSystemException;
 public class PostgreSqlDriver implements DatabaseDriver {
 
 	// DO NOT RENAME
-	private String errorBuffer;
 	private String host;
+	private Integer port;
+	private String socket;
+	private String url;
 	private String username;
 	private String password;
 	private String database;
+	private PostgreSqlSslMode sslMode;
+	private String sslRootCert;
+	private String sslClientCert;
+	private String sslClientKey;
+	private Integer minPoolSize;
+	private Integer maxPoolSize;
+	private Long idleTimeoutMillis;
+	private Long maxLifetimeMillis;
 	// END
 	
 	/**
-	 * Pointer to heap memory where the PostgreSQL connection pool lives.
+	 * Handle to the PostgreSQL connection pool in the native handle registry.
 	 * 
 	 * <h2> SAFETY </h2>
 	 * This value should <strong>NEVER</strong> be altered, except by:
 	 * <ul>
-	 * 	<li> {@link PostgreSqlDriver#PostgreSqlDriver(String, String, String, String) }
+	 * 	<li> {@link PostgreSqlDriver#PostgreSqlDriver(String, Integer, String, String, String, String, String, String, PostgreSqlSslMode, String, String, String, Integer, Integer, Long, Long) }
 	 * 	<li> {@link PostgreSqlDriver#unload() }
 	 * </ul>
 	 * 
-	 * Otherwhise altering this variable is <strong>guaranteed</strong> to result in Undefined Behaviour, and likely a segmentation fault
+	 * Otherwise calls to the native library will fail with an invalid handle error
 	 */
 	private long ptr;
 	private volatile boolean ptrValid = false;
 	
+	/**
+	 * Guards {@link #ptr} against being freed while a statement is using it.
+	 * Statements take the read lock, so they can run concurrently on the connection pool, {@link #unload()} takes the write lock
+	 */
+	private final ReadWriteLock ptrLock = new ReentrantReadWriteLock();
+	
 	private PostgreSqlDriver() {}
 	
 	/**
@@ -43,16 +72,38 @@ public class PostgreSqlDriver implements DatabaseDriver {
 	 * 
 	 * <h2> Thread Safety </h2>
 	 * It is up to the implementer to guarantee that this method is <strong>NOT</strong> called from two threads simultaneously, this can result in Undefined Behaviour
-	 * @param host The PostgreSQL host
+	 * @param host The PostgreSQL host, or null
+	 * @param port The PostgreSQL port, or null for the default
+	 * @param socket The path to the PostgreSQL Unix domain socket, or null
+	 * @param url The PostgreSQL connection URL, or null
 	 * @param username The PostgreSQL username
 	 * @param password The PostgreSQL password
 	 * @param database The PostgreSQL database
+	 * @param sslMode The SSL mode to connect with, or null to disable TLS
+	 * @param sslRootCert Path to the PEM encoded CA bundle used to verify the server, or null
+	 * @param sslClientCert Path to the PEM encoded client certificate, or null
+	 * @param sslClientKey Path to the PEM encoded PKCS#8 client key, or null
+	 * @param minPoolSize The minimum number of idle connections in the pool, or null for the default
+	 * @param maxPoolSize The maximum number of connections in the pool, or null for the default
+	 * @param idleTimeoutMillis The time after which an idle connection is closed, or null for the default
+	 * @param maxLifetimeMillis The time after which a connection is closed, or null for the default
 	 */
-	protected PostgreSqlDriver(String host, String username, String password, String database) {
+	protected PostgreSqlDriver(String host, Integer port, String socket, String url, String username, String password, String database, PostgreSqlSslMode sslMode, String sslRootCert, String sslClientCert, String sslClientKey, Integer minPoolSize, Integer maxPoolSize, Long idleTimeoutMillis, Long maxLifetimeMillis) {
 		this.host = host;
+		this.port = port;
+		this.socket = socket;
+		this.url = url;
 		this.username = username;		
 		this.password = password;
 		this.database = database;
+		this.sslMode = sslMode;
+		this.sslRootCert = sslRootCert;
+		this.sslClientCert = sslClientCert;
+		this.sslClientKey = sslClientKey;
+		this.minPoolSize = minPoolSize;
+		this.maxPoolSize = maxPoolSize;
+		this.idleTimeoutMillis = idleTimeoutMillis;
+		this.maxLifetimeMillis = maxLifetimeMillis;
 	}
 	
 	/**
@@ -60,16 +111,12 @@ public class PostgreSqlDriver implements DatabaseDriver {
 	 * @throws IOException When saving the native library failed
 	 * @throws UnsatisfiedLinkError When loading the native library failed
 	 * @throws UnsupportedOperatingSystemException When the current operating system is unsupported
-	 * @throws RuntimeException When {@link PostgreSqlDriver#initializeNative()} returns an error
+	 * @throws DriverLoadException When the driver could not be initialized, e.g. because the configuration is invalid
 	 */
 	public void loadDriver() throws IOException {
 		DriverManager.loadLibrary();
 		
 		this.ptr = initializeNative();
-		if(this.ptr == 0) {
-			throw new RuntimeException("Failed to load driver: " + String.valueOf(this.errorBuffer));
-		}
-		
 		this.ptrValid = true;
 	}
 	
@@ -100,75 +147,380 @@ public class PostgreSqlDriver implements DatabaseDriver {
 		return DriverManager.isLoaded() && this.ptrValid;
 	}
 	
+	/**
+	 * Query the PostgreSQL database. This method may be called from multiple threads at the same time, each query uses its own connection from the pool.
+	 * If the statement has a fetch size, the rows are read from a server-side cursor that many rows at a time
+	 * @param statement The statement to query with
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
 	@Override
-	public synchronized SqlRow[] query(PreparedStatement statement) throws SqlException {
-		checkValid();
-		
-		if(!statement.allBound()) {
-			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+	public SqlRow[] query(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+			
+			return this.queryNative(this.ptr, statement.getStmt(), statement.getParameters(), statement.getFetchSize());
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
-		
-		SqlRow[] resultSet = this.queryNative(this.ptr, statement.getStmt(), statement.getParameters());
-		if(resultSet == null) {
-			String buffer = this.errorBuffer;
-			this.errorBuffer = "";
-			throw new SqlException(buffer);
+	};
+	
+	/**
+	 * Query the PostgreSQL database, reading the rows as the result set is iterated. This method may be called from multiple threads at the same time.
+	 * The result set holds on to a connection from the pool until all rows have been read, or until it is closed.
+	 * If the statement has a fetch size, the rows are read from a server-side cursor, so only that many rows are held in memory at a time
+	 * @param statement The statement to query with
+	 * @return The result set
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public ResultSet queryStream(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+			
+			return new PostgreSqlResultSet(this.queryStreamNative(this.ptr, statement.getStmt(), statement.getParameters(), statement.getFetchSize()), statement.getFetchSize());
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Query the PostgreSQL database, returning the result per column. This is faster than {@link #query(PreparedStatement)} for large results,
+	 * as no object is created per row or value. This method may be called from multiple threads at the same time
+	 * @param statement The statement to query with
+	 * @return The result
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public ColumnarResult queryColumnar(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+			
+			return ColumnarBuffer.decode(this.queryColumnarNative(this.ptr, statement.getStmt(), statement.getParameters()));
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
+	}
 
-		return resultSet;
-	};
+	/**
+	 * Execute a {@link PreparedStatement}. This method may be called from multiple threads at the same time, each statement uses its own connection from the pool
+	 * @param statement The statemenent to execute
+	 * @return The number of affected rows
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public ExecuteResult execute(PreparedStatement statement) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+	
+			return this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
 
+	/**
+	 * Execute a {@link PreparedStatement} and return the key columns of the rows it inserted or updated.
+	 * A RETURNING clause for the key columns is appended to the statement, so the statement must not have one of its own
+	 * @param statement The statemenent to execute
+	 * @param keyColumns The columns to return. The names are quoted, so they are case sensitive. If there are none, all columns are returned
+	 * @return The rows returned by the RETURNING clause
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws IllegalArgumentException When a key column is null or empty
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
 	@Override
-	public synchronized void execute(PreparedStatement statement) throws SqlException {
-		checkValid();
-		
-		if(!statement.allBound()) {
-			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+	public SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!statement.allBound()) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound");
+			}
+	
+			return this.executeGeneratedKeysNative(this.ptr, withReturningClause(statement.getStmt(), keyColumns), statement.getParameters());
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
+	}
 
-		int status = this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
-		if(status != 0) {
-			String buffer = this.errorBuffer;
-			this.errorBuffer = "";
-			throw new SqlException(buffer);
+	/**
+	 * Execute a {@link PreparedStatement} once for every set of parameters. The statement is prepared once, and all sets are executed
+	 * on one connection in a single transaction: if one of them fails, none of them are applied. Parameters bound on the statement itself are ignored
+	 * @param statement The statemenent to execute
+	 * @param parameterSets The parameters to execute the statement with, one array per execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws UnboundPreparedStatementException When a parameter set does not bind every parameter in the statement
+	 * @throws SqlException When the statement failed. Well known errors are thrown as a subclass, e.g. a UniqueViolationException or a DeadlockException
+	 */
+	@Override
+	public long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			if(!parameterSets.stream().allMatch(statement::isBoundBy)) {
+				throw new UnboundPreparedStatementException("Not all paramaters are bound in every parameter set");
+			}
+	
+			return this.executeBatchNative(this.ptr, statement.getStmt(), parameterSets.toArray(new SqlParameter[0][]));
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+
+	/**
+	 * Copy data into a table with a {@code COPY ... FROM STDIN} statement. The data is read from the stream in chunks
+	 * and sent to the database as it is read, so it is never held in memory as a whole
+	 * @param copyStatement The COPY statement, e.g. {@code COPY foo (a, b) FROM STDIN WITH (FORMAT csv)}
+	 * @param data The data to copy, in the format given in the statement. The stream is not closed
+	 * @return The number of rows copied
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When reading from the stream failed. The copy is aborted
+	 */
+	public long copyIn(String copyStatement, InputStream data) throws SqlException, IOException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			return this.copyInNative(this.ptr, copyStatement, data);
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Copy data out of the database with a {@code COPY ... TO STDOUT} statement. Every chunk of data is written to the stream
+	 * as soon as it is received, so the result is never held in memory as a whole
+	 * @param copyStatement The COPY statement, e.g. {@code COPY foo TO STDOUT WITH (FORMAT binary)}
+	 * @param out The stream to write the data to. The stream is neither flushed nor closed
+	 * @return The number of bytes written
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When writing to the stream failed. The copy is aborted
+	 */
+	public long copyOut(String copyStatement, OutputStream out) throws SqlException, IOException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			return this.copyOutNative(this.ptr, copyStatement, out);
+		} finally {
+			this.ptrLock.readLock().unlock();
+		}
+	}
+	
+	/**
+	 * Begin a transaction. The transaction holds on to one connection from the pool until it is committed or rolled back.
+	 * Unloading the driver does not end transactions which are still active
+	 * @param options The options to begin the transaction with
+	 * @return The transaction
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 * @throws SqlException When beginning the transaction failed
+	 */
+	@Override
+	public Transaction beginTransaction(TransactionOptions options) throws SqlException {
+		this.ptrLock.readLock().lock();
+		try {
+			checkValid();
+			
+			long transactionPtr = this.beginTransactionNative(this.ptr, options);
+			return new PostgreSqlTransaction(transactionPtr);
+		} finally {
+			this.ptrLock.readLock().unlock();
 		}
 	}
 
+	/**
+	 * Unload the driver. Waits for running statements to finish
+	 * @throws IllegalStateException When the native library is not loaded
+	 * @throws InvalidHandleException When the driver's native handle is invalid
+	 * @throws DriverUnloadedException When {@link #unload()} has already been called
+	 */
 	@Override
-	public synchronized void unload() {
-		checkValid();
-		this.ptrValid = false;
-		this.unloadNative(this.ptr);
-		this.ptr = 0;	
+	public void unload() {
+		this.ptrLock.writeLock().lock();
+		try {
+			checkValid();
+			this.ptrValid = false;
+			try {
+				this.unloadNative(this.ptr);
+			} finally {
+				this.ptr = 0;
+			}
+		} finally {
+			this.ptrLock.writeLock().unlock();
+		}
+	}
+	
+	/**
+	 * Append a RETURNING clause for the key columns to a statement. The clause is put on a line of its own, so a trailing {@code --} comment does not hide it
+	 * @param statement The statement
+	 * @param keyColumns The columns to return, or none to return all columns
+	 * @return The statement with the RETURNING clause
+	 * @throws IllegalArgumentException When a key column is null or empty
+	 */
+	static String withReturningClause(String statement, String[] keyColumns) {
+		String stripped = statement.strip();
+		if(stripped.endsWith(";")) {
+			stripped = stripped.substring(0, stripped.length() - 1).strip();
+		}
+		
+		if(keyColumns.length == 0) {
+			return stripped + "\nRETURNING *";
+		}
+		
+		StringJoiner columns = new StringJoiner(", ");
+		for(String column : keyColumns) {
+			if(column == null || column.isEmpty()) {
+				throw new IllegalArgumentException("Key columns may not be null or empty");
+			}
+			
+			columns.add('"' + column.replace("\"", "\"\"") + '"');
+		}
+		
+		return stripped + "\nRETURNING " + columns;
 	}
 	
 	/**
 	 * Initialize the driver
-	 * @return Returns a heap pointer to where the postgres connection pool is stored
+	 * @return Returns a handle to the postgres connection pool
+	 * @throws DriverLoadException When the driver could not be initialized
 	 */
 	private synchronized native long initializeNative();
 	
 	/**
 	 * Execute a statement
-	 * @param ptr The heap pointer to where the postgres connection pool is stored
+	 * @param ptr The handle to the postgres connection pool
+	 * @param preparedStatement The statement to execute, with all params bound
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
+	 */
+	private native ExecuteResult executeNative(long ptr, String preparedStatement, SqlParameter[] parameter) throws SqlException;
+	
+	/**
+	 * Execute a statement and collect the rows returned by its RETURNING clause
+	 * @param ptr The handle to the postgres connection pool
 	 * @param preparedStatement The statement to execute, with all params bound
-	 * @return -1 if an error occurred. 0 if everything is OK.
+	 * @return The returned rows
+	 * @throws SqlException When the statement failed
 	 */
-	private synchronized native int executeNative(long ptr, String preparedStatement, SqlParameter[] parameter);
+	private native SqlRow[] executeGeneratedKeysNative(long ptr, String preparedStatement, SqlParameter[] parameter) throws SqlException;
+	
+	/**
+	 * Execute a statement once for every parameter set
+	 * @param ptr The handle to the postgres connection pool
+	 * @param preparedStatement The statement to execute
+	 * @param parameterSets The parameters for every execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long[] executeBatchNative(long ptr, String preparedStatement, SqlParameter[][] parameterSets) throws SqlException;
+	
+	/**
+	 * Copy data from an InputStream into the database
+	 * @param ptr The handle to the postgres connection pool
+	 * @param copyStatement The COPY ... FROM STDIN statement
+	 * @param data The stream to read the data from
+	 * @return The number of rows copied
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When reading from the stream failed
+	 */
+	private native long copyInNative(long ptr, String copyStatement, InputStream data) throws SqlException, IOException;
+	
+	/**
+	 * Copy data from the database into an OutputStream
+	 * @param ptr The handle to the postgres connection pool
+	 * @param copyStatement The COPY ... TO STDOUT statement
+	 * @param out The stream to write the data to
+	 * @return The number of bytes written
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When writing to the stream failed
+	 */
+	private native long copyOutNative(long ptr, String copyStatement, OutputStream out) throws SqlException, IOException;
 	
 	/**
 	 * Query the database
-	 * @param ptr The heap pointer to where the postgres connection pool is stored
+	 * @param ptr The handle to the postgres connection pool
 	 * @param preparedStatement The statement to query with, with all params bound
-	 * @return The data returned by the database, or null if an error occurred
+	 * @param fetchSize The number of rows to read from the server at a time, 0 to read all rows at once
+	 * @return The data returned by the database
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] queryNative(long ptr, String preparedStatement, SqlParameter[] parameters, int fetchSize) throws SqlException;
+	
+	/**
+	 * Query the database without reading the rows
+	 * @param ptr The handle to the postgres connection pool
+	 * @param preparedStatement The statement to query with, with all params bound
+	 * @param fetchSize The number of rows to read from the server at a time using a server-side cursor, 0 to not use a cursor
+	 * @return The handle to the result set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long queryStreamNative(long ptr, String preparedStatement, SqlParameter[] parameters, int fetchSize) throws SqlException;
+	
+	/**
+	 * Query the database, encoding the result per column
+	 * @param ptr The handle to the postgres connection pool
+	 * @param preparedStatement The statement to query with, with all params bound
+	 * @return The handle to the encoded result
+	 * @throws SqlException When the statement failed
+	 */
+	private native long queryColumnarNative(long ptr, String preparedStatement, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Begin a transaction
+	 * @param ptr The handle to the postgres connection pool
+	 * @param options The options to begin the transaction with
+	 * @return Returns a handle to the transaction
+	 * @throws SqlException When beginning the transaction failed
 	 */
-	private synchronized native SqlRow[] queryNative(long ptr, String preparedStatement, SqlParameter[] parameters);
+	private native long beginTransactionNative(long ptr, TransactionOptions options) throws SqlException;
 	
 	/**
-	 * Unload the driver. This will destory the postgres connection pool and free it's memory
-	 * @param ptr The heap pointer to where the postgres connection pool is stored
+	 * Unload the driver. This will destroy the postgres connection pool and invalidate its handle
+	 * @param ptr The handle to the postgres connection pool
+	 * @throws InvalidHandleException When the handle is invalid
 	 */
-	private synchronized native void unloadNative(long ptr);
+	private native void unloadNative(long ptr);
 
 	
 	
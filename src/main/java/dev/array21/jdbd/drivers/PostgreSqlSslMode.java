@@ -0,0 +1,31 @@
+package dev.array21.jdbd.drivers;
+
+/**
+ * SSL mode of the PostgreSQL connection, following the semantics of libpq's {@code sslmode}
+ */
+public enum PostgreSqlSslMode {
+	/**
+	 * Do not use TLS
+	 */
+	DISABLE,
+	
+	/**
+	 * Use TLS if the server supports it, without verifying the server's certificate
+	 */
+	PREFER,
+	
+	/**
+	 * Require TLS. The server's certificate is only verified if a root certificate is configured
+	 */
+	REQUIRE,
+	
+	/**
+	 * Require TLS and verify that the server's certificate is signed by a trusted CA
+	 */
+	VERIFY_CA,
+	
+	/**
+	 * Require TLS, verify that the server's certificate is signed by a trusted CA and that the hostname matches the certificate
+	 */
+	VERIFY_FULL
+}
@@ -0,0 +1,339 @@
+package dev.array21.jdbd.drivers;
+
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.util.List;
+
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+import dev.array21.jdbd.exceptions.UnboundPreparedStatementException;
+
+public class PostgreSqlTransaction implements Transaction {
+	
+	/**
+	 * Handle to the transaction in the native handle registry.
+	 * Becomes invalid once the transaction has been committed or rolled back
+	 */
+	private final long ptr;
+	private boolean active = true;
+	
+	protected PostgreSqlTransaction(long ptr) {
+		this.ptr = ptr;
+	}
+	
+	/**
+	 * Check if the transaction can still be used
+	 * @throws IllegalStateException When the native library is not loaded, or the transaction has already been committed or rolled back
+	 */
+	private void checkActive() {
+		if(!DriverManager.isLoaded()) {
+			throw new IllegalStateException("libjdbd is not loaded");
+		}
+		
+		if(!this.active) {
+			throw new IllegalStateException("Transaction has already been committed or rolled back");
+		}
+	}
+	
+	/**
+	 * Check if the transaction is active
+	 * @return True if it has not been committed or rolled back yet, false otherwise
+	 */
+	@Override
+	public synchronized boolean isActive() {
+		return this.active;
+	}
+	
+	/**
+	 * Query the PostgreSQL database within this transaction.
+	 * If the statement has a fetch size, the rows are read from a server-side cursor that many rows at a time
+	 * @param statement The statement to query with
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed, or when a column has a type which is not supported
+	 */
+	@Override
+	public synchronized SqlRow[] query(PreparedStatement statement) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.queryNative(this.ptr, statement.getStmt(), statement.getParameters(), statement.getFetchSize());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction
+	 * @param statement The statement to execute
+	 * @return The result of the statement
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized ExecuteResult execute(PreparedStatement statement) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.executeNative(this.ptr, statement.getStmt(), statement.getParameters());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction and return the key columns of the rows it inserted or updated.
+	 * A RETURNING clause for the key columns is appended to the statement, so the statement must not have one of its own
+	 * @param statement The statement to execute
+	 * @param keyColumns The columns to return. The names are quoted, so they are case sensitive. If there are none, all columns are returned
+	 * @return The rows returned by the RETURNING clause
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When not all parameters in the statement have been bound
+	 * @throws IllegalArgumentException When a key column is null or empty
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+		checkActive();
+		
+		if(!statement.allBound()) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound");
+		}
+		
+		return this.executeGeneratedKeysNative(this.ptr, PostgreSqlDriver.withReturningClause(statement.getStmt(), keyColumns), statement.getParameters());
+	}
+	
+	/**
+	 * Execute a {@link PreparedStatement} within this transaction once for every set of parameters. The statement is prepared once.
+	 * Parameters bound on the statement itself are ignored
+	 * @param statement The statement to execute
+	 * @param parameterSets The parameters to execute the statement with, one array per execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws UnboundPreparedStatementException When a parameter set does not bind every parameter in the statement
+	 * @throws SqlException When the statement failed
+	 */
+	@Override
+	public synchronized long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+		checkActive();
+		
+		if(!parameterSets.stream().allMatch(statement::isBoundBy)) {
+			throw new UnboundPreparedStatementException("Not all paramaters are bound in every parameter set");
+		}
+		
+		return this.executeBatchNative(this.ptr, statement.getStmt(), parameterSets.toArray(new SqlParameter[0][]));
+	}
+	
+	/**
+	 * Copy data into a table with a {@code COPY ... FROM STDIN} statement within this transaction. The data is read from the stream in chunks
+	 * and sent to the database as it is read, so it is never held in memory as a whole
+	 * @param copyStatement The COPY statement, e.g. {@code COPY foo (a, b) FROM STDIN WITH (FORMAT csv)}
+	 * @param data The data to copy, in the format given in the statement. The stream is not closed
+	 * @return The number of rows copied
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When reading from the stream failed. The copy is aborted
+	 */
+	public synchronized long copyIn(String copyStatement, InputStream data) throws SqlException, IOException {
+		checkActive();
+		return this.copyInNative(this.ptr, copyStatement, data);
+	}
+	
+	/**
+	 * Copy data out of the database with a {@code COPY ... TO STDOUT} statement within this transaction. Every chunk of data is written to the stream
+	 * as soon as it is received, so the result is never held in memory as a whole
+	 * @param copyStatement The COPY statement, e.g. {@code COPY foo TO STDOUT WITH (FORMAT binary)}
+	 * @param out The stream to write the data to. The stream is neither flushed nor closed
+	 * @return The number of bytes written
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When writing to the stream failed. The copy is aborted
+	 */
+	public synchronized long copyOut(String copyStatement, OutputStream out) throws SqlException, IOException {
+		checkActive();
+		return this.copyOutNative(this.ptr, copyStatement, out);
+	}
+	
+	/**
+	 * Commit the transaction. The transaction can not be used anymore afterwards, even if committing failed
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When committing failed
+	 */
+	@Override
+	public synchronized void commit() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.commitNative(this.ptr);
+	}
+	
+	/**
+	 * Roll back the transaction. The transaction can not be used anymore afterwards, even if rolling back failed
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When rolling back failed
+	 */
+	@Override
+	public synchronized void rollback() throws SqlException {
+		checkActive();
+		this.active = false;
+		this.rollbackNative(this.ptr);
+	}
+	
+	@Override
+	public synchronized void close() throws SqlException {
+		if(this.active) {
+			this.rollback();
+		}
+	}
+	
+	/**
+	 * Begin a transaction nested in this transaction, backed by a savepoint
+	 * @return The nested transaction
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	@Override
+	public synchronized Transaction beginTransaction() throws SqlException {
+		checkActive();
+		return NestedTransaction.begin(this, this);
+	}
+	
+	/**
+	 * Create a savepoint. Creating a savepoint with the name of an existing savepoint replaces it
+	 * @param name The name of the savepoint. May only contain letters, digits and underscores
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	@Override
+	public synchronized void savepoint(String name) throws SqlException {
+		checkActive();
+		this.savepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Release a savepoint, keeping the changes made since it was created
+	 * @param name The name of the savepoint
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When releasing the savepoint failed
+	 */
+	@Override
+	public synchronized void releaseSavepoint(String name) throws SqlException {
+		checkActive();
+		this.releaseSavepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Undo all changes made since a savepoint was created. The savepoint itself is kept
+	 * @param name The name of the savepoint
+	 * @throws IllegalStateException When the transaction has already been committed or rolled back
+	 * @throws SqlException When rolling back to the savepoint failed
+	 */
+	@Override
+	public synchronized void rollbackToSavepoint(String name) throws SqlException {
+		checkActive();
+		this.rollbackToSavepointNative(this.ptr, name);
+	}
+	
+	/**
+	 * Execute a statement
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute, with all params bound
+	 * @return The result of the statement
+	 * @throws SqlException When the statement failed
+	 */
+	private native ExecuteResult executeNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement and collect its generated keys
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute, with all params bound
+	 * @return The generated keys
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] executeGeneratedKeysNative(long ptr, String rawStmt, SqlParameter[] parameters) throws SqlException;
+	
+	/**
+	 * Execute a statement once for every parameter set
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to execute
+	 * @param parameterSets The parameters for every execution
+	 * @return The number of affected rows for every parameter set
+	 * @throws SqlException When the statement failed
+	 */
+	private native long[] executeBatchNative(long ptr, String rawStmt, SqlParameter[][] parameterSets) throws SqlException;
+	
+	/**
+	 * Copy data from an InputStream into the database
+	 * @param ptr The handle to the transaction
+	 * @param copyStatement The COPY ... FROM STDIN statement
+	 * @param data The stream to read the data from
+	 * @return The number of rows copied
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When reading from the stream failed
+	 */
+	private native long copyInNative(long ptr, String copyStatement, InputStream data) throws SqlException, IOException;
+	
+	/**
+	 * Copy data from the database into an OutputStream
+	 * @param ptr The handle to the transaction
+	 * @param copyStatement The COPY ... TO STDOUT statement
+	 * @param out The stream to write the data to
+	 * @return The number of bytes written
+	 * @throws SqlException When the copy failed
+	 * @throws IOException When writing to the stream failed
+	 */
+	private native long copyOutNative(long ptr, String copyStatement, OutputStream out) throws SqlException, IOException;
+	
+	/**
+	 * Query the database
+	 * @param ptr The handle to the transaction
+	 * @param rawStmt The statement to query with, with all params bound
+	 * @param fetchSize The number of rows to read from the server at a time, 0 to read all rows at once
+	 * @return The data returned by the database
+	 * @throws SqlException When the statement failed
+	 */
+	private native SqlRow[] queryNative(long ptr, String rawStmt, SqlParameter[] parameters, int fetchSize) throws SqlException;
+	
+	/**
+	 * Commit the transaction. This invalidates its handle
+	 * @param ptr The handle to the transaction
+	 * @throws SqlException When committing failed
+	 */
+	private native void commitNative(long ptr) throws SqlException;
+	
+	/**
+	 * Roll back the transaction. This invalidates its handle
+	 * @param ptr The handle to the transaction
+	 * @throws SqlException When rolling back failed
+	 */
+	private native void rollbackNative(long ptr) throws SqlException;
+	
+	/**
+	 * Create a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When creating the savepoint failed
+	 */
+	private native void savepointNative(long ptr, String name) throws SqlException;
+	
+	/**
+	 * Release a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When releasing the savepoint failed
+	 */
+	private native void releaseSavepointNative(long ptr, String name) throws SqlException;
+	
+	/**
+	 * Roll back to a savepoint
+	 * @param ptr The handle to the transaction
+	 * @param name The name of the savepoint
+	 * @throws SqlException When rolling back to the savepoint failed
+	 */
+	private native void rollbackToSavepointNative(long ptr, String name) throws SqlException;
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when the connection to the database was lost while running a statement
+ */
+public class ConnectionLostException extends SqlException {
+	private static final long serialVersionUID = 51320282205159478L;
+
+	public ConnectionLostException(String msg) {
+		super(msg);
+	}
+	
+	public ConnectionLostException(String msg, String sqlState, Integer vendorCode, String detail, String hint, String constraint, String table, String column) {
+		super(msg, sqlState, vendorCode, detail, hint, constraint, table, column);
+	}
+}
\ No newline at end of file
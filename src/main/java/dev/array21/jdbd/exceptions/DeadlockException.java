@@ -0,0 +1,16 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when a statement was aborted because of a deadlock. The statement can be retried
+ */
+public class DeadlockException extends SqlException {
+	private static final long serialVersionUID = 3258498773874573141L;
+
+	public DeadlockException(String msg) {
+		super(msg);
+	}
+	
+	public DeadlockException(String msg, String sqlState, Integer vendorCode, String detail, String hint, String constraint, String table, String column) {
+		super(msg, sqlState, vendorCode, detail, hint, constraint, table, column);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when the native library fails in a way unrelated to the database, e.g. when a Java value could not be converted
+ */
+public class DriverException extends RuntimeException {
+	private static final long serialVersionUID = -8622283660449058605L;
+
+	public DriverException() {
+		super();
+	}
+	
+	public DriverException(String msg) {
+		super(msg);
+	}
+	
+	public DriverException(String msg, Throwable cause) {
+		super(msg, cause);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when a driver could not be loaded, e.g. because its configuration is invalid or the database could not be reached
+ */
+public class DriverLoadException extends DriverException {
+	private static final long serialVersionUID = 1440530349523806572L;
+
+	public DriverLoadException() {
+		super();
+	}
+	
+	public DriverLoadException(String msg) {
+		super(msg);
+	}
+	
+	public DriverLoadException(String msg, Throwable cause) {
+		super(msg, cause);
+	}
+}
\ No newline at end of file
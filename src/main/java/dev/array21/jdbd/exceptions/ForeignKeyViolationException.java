@@ -0,0 +1,16 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when a statement violates a foreign key constraint
+ */
+public class ForeignKeyViolationException extends SqlException {
+	private static final long serialVersionUID = -6667770931565106180L;
+
+	public ForeignKeyViolationException(String msg) {
+		super(msg);
+	}
+	
+	public ForeignKeyViolationException(String msg, String sqlState, Integer vendorCode, String detail, String hint, String constraint, String table, String column) {
+		super(msg, sqlState, vendorCode, detail, hint, constraint, table, column);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when a driver's native handle is not, or no longer, valid
+ */
+public class InvalidHandleException extends DriverException {
+	private static final long serialVersionUID = 5969161179476892218L;
+
+	public InvalidHandleException() {
+		super();
+	}
+	
+	public InvalidHandleException(String msg) {
+		super(msg);
+	}
+	
+	public InvalidHandleException(String msg, Throwable cause) {
+		super(msg, cause);
+	}
+}
\ No newline at end of file
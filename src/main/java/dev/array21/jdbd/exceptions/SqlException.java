@@ -1,8 +1,18 @@
 package dev.array21.jdbd.exceptions;
 
+import dev.array21.jdbd.annotations.Nullable;
+
 public class SqlException extends Exception {
 	private static final long serialVersionUID = -5682698385934512793L;
 
+	private String sqlState;
+	private Integer vendorCode;
+	private String detail;
+	private String hint;
+	private String constraint;
+	private String table;
+	private String column;
+	
 	public SqlException() {
 		super();
 	}
@@ -11,4 +21,92 @@ public class SqlException extends Exception {
 		super(msg);
 	}
 	
+	public SqlException(String msg, Throwable cause) {
+		super(msg, cause);
+	}
+	
+	/**
+	 * Create an SqlException for an error reported by the database
+	 * @param msg The error message
+	 * @param sqlState The SQLSTATE of the error, or null
+	 * @param vendorCode The database specific error code, or null
+	 * @param detail Details about the error, or null
+	 * @param hint A suggestion on how to resolve the error, or null
+	 * @param constraint The name of the constraint which was violated, or null
+	 * @param table The name of the table the error relates to, or null
+	 * @param column The name of the column the error relates to, or null
+	 */
+	public SqlException(String msg, String sqlState, Integer vendorCode, String detail, String hint, String constraint, String table, String column) {
+		super(msg);
+		this.sqlState = sqlState;
+		this.vendorCode = vendorCode;
+		this.detail = detail;
+		this.hint = hint;
+		this.constraint = constraint;
+		this.table = table;
+		this.column = column;
+	}
+	
+	/**
+	 * Get the five character SQLSTATE of the error
+	 * @return The SQLSTATE, or null if the error was not reported by the database
+	 */
+	@Nullable
+	public String getSqlState() {
+		return this.sqlState;
+	}
+	
+	/**
+	 * Get the database specific error code. Only MySQL reports these, PostgreSQL only uses the SQLSTATE
+	 * @return The error code, or null if there is none
+	 */
+	@Nullable
+	public Integer getVendorCode() {
+		return this.vendorCode;
+	}
+	
+	/**
+	 * Get details about the error. Only reported by PostgreSQL
+	 * @return The details, or null if there are none
+	 */
+	@Nullable
+	public String getDetail() {
+		return this.detail;
+	}
+	
+	/**
+	 * Get a suggestion on how to resolve the error. Only reported by PostgreSQL
+	 * @return The hint, or null if there is none
+	 */
+	@Nullable
+	public String getHint() {
+		return this.hint;
+	}
+	
+	/**
+	 * Get the name of the constraint which was violated. Only reported by PostgreSQL
+	 * @return The name of the constraint, or null if there is none
+	 */
+	@Nullable
+	public String getConstraint() {
+		return this.constraint;
+	}
+	
+	/**
+	 * Get the name of the table the error relates to. Only reported by PostgreSQL
+	 * @return The name of the table, or null if there is none
+	 */
+	@Nullable
+	public String getTable() {
+		return this.table;
+	}
+	
+	/**
+	 * Get the name of the column the error relates to. Only reported by PostgreSQL
+	 * @return The name of the column, or null if there is none
+	 */
+	@Nullable
+	public String getColumn() {
+		return this.column;
+	}
 }
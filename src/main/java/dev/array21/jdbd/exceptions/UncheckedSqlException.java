@@ -0,0 +1,24 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Wraps an {@link SqlException} where a checked exception can not be thrown, e.g. when iterating over a {@link dev.array21.jdbd.ResultSet}
+ */
+public class UncheckedSqlException extends RuntimeException {
+	private static final long serialVersionUID = 3349826148520416471L;
+
+	public UncheckedSqlException(SqlException cause) {
+		super(cause.getMessage(), cause);
+	}
+	
+	public UncheckedSqlException(String msg, SqlException cause) {
+		super(msg, cause);
+	}
+	
+	/**
+	 * @return The wrapped SqlException
+	 */
+	@Override
+	public synchronized SqlException getCause() {
+		return (SqlException) super.getCause();
+	}
+}
@@ -0,0 +1,16 @@
+package dev.array21.jdbd.exceptions;
+
+/**
+ * Thrown when a statement violates a unique or primary key constraint
+ */
+public class UniqueViolationException extends SqlException {
+	private static final long serialVersionUID = 2088452442651338350L;
+
+	public UniqueViolationException(String msg) {
+		super(msg);
+	}
+	
+	public UniqueViolationException(String msg, String sqlState, Integer vendorCode, String detail, String hint, String constraint, String table, String column) {
+		super(msg, sqlState, vendorCode, detail, hint, constraint, table, column);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertArrayEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class ColumnVectorTest {
+
+	@Test
+	public void testIsNull() {
+		LongColumnVector column = new LongColumnVector("a", new byte[] { (byte) 0b1000_0001, 0b1 }, new long[9]);
+		assertTrue(column.isNull(0));
+		assertFalse(column.isNull(1));
+		assertTrue(column.isNull(7));
+		assertTrue(column.isNull(8));
+	}
+	
+	@Test(expected = IndexOutOfBoundsException.class)
+	public void testIsNullOutOfBounds() {
+		new LongColumnVector("a", new byte[1], new long[1]).isNull(1);
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testInvalidNullBitmap() {
+		new DoubleColumnVector("a", new byte[2], new double[8]);
+	}
+	
+	@Test
+	public void testGetBytes() {
+		BytesColumnVector column = new BytesColumnVector("a", new byte[] { 0b10 }, new int[] { 0, 2, 2 }, new byte[] { 0x1, 0x2 });
+		assertArrayEquals(new byte[] { 0x1, 0x2 }, column.getBytes(0));
+		assertNull(column.getBytes(1));
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testInvalidOffsets() {
+		new BytesColumnVector("a", new byte[1], new int[] { 0, 3 }, new byte[2]);
+	}
+}
@@ -0,0 +1,115 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertArrayEquals;
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertTrue;
+
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.nio.charset.StandardCharsets;
+
+import org.junit.Before;
+import org.junit.Test;
+
+import dev.array21.jdbd.exceptions.SqlTypeMismatchException;
+
+public class ColumnarResultTest {
+
+	private ByteBuffer buffer;
+	
+	/**
+	 * Encode a result with three rows, like the native library does
+	 */
+	@Before
+	public void setup() {
+		this.buffer = ByteBuffer.allocate(256).order(ByteOrder.LITTLE_ENDIAN);
+		this.buffer.putInt(3).putInt(3);
+		
+		putName(this.buffer, "a");
+		this.buffer.put((byte) 0).put((byte) 0b010);
+		this.buffer.putLong(1).putLong(0).putLong(3);
+		
+		putName(this.buffer, "b");
+		this.buffer.put((byte) 1).put((byte) 0);
+		this.buffer.putDouble(0.5d).putDouble(1.5d).putDouble(2.5d);
+		
+		putName(this.buffer, "c");
+		this.buffer.put((byte) 2).put((byte) 0b100);
+		this.buffer.putInt(0).putInt(3).putInt(5).putInt(5);
+		this.buffer.put("fooba".getBytes(StandardCharsets.UTF_8));
+		
+		this.buffer.flip();
+	}
+	
+	private static void putName(ByteBuffer buffer, String name) {
+		byte[] bytes = name.getBytes(StandardCharsets.UTF_8);
+		buffer.putInt(bytes.length).put(bytes);
+	}
+	
+	@Test
+	public void testDecode() {
+		ColumnarResult result = ColumnarResult.decode(this.buffer);
+		assertEquals(3, result.getRowCount());
+		assertEquals(3, result.getColumnCount());
+		assertEquals("a", result.getColumn(0).getName());
+		
+		LongColumnVector a = result.getLongColumn("a");
+		assertArrayEquals(new long[] { 1, 0, 3 }, a.getValues());
+		assertFalse(a.isNull(0));
+		assertTrue(a.isNull(1));
+		
+		DoubleColumnVector b = result.getDoubleColumn("b");
+		assertArrayEquals(new double[] { 0.5d, 1.5d, 2.5d }, b.getValues(), 0d);
+		
+		StringColumnVector c = result.getStringColumn("c");
+		assertEquals("foo", c.getString(0));
+		assertEquals("ba", c.getString(1));
+		assertNull(c.getString(2));
+	}
+	
+	@Test
+	public void testDecodeKeepsPosition() {
+		ColumnarResult.decode(this.buffer);
+		assertEquals(0, this.buffer.position());
+	}
+	
+	@Test
+	public void testDecodeEmpty() {
+		ByteBuffer buffer = ByteBuffer.allocate(8).order(ByteOrder.LITTLE_ENDIAN).putInt(0).putInt(0);
+		buffer.flip();
+		
+		ColumnarResult result = ColumnarResult.decode(buffer);
+		assertEquals(0, result.getRowCount());
+		assertEquals(0, result.getColumnCount());
+	}
+	
+	@Test
+	public void testStringColumnAsBytes() {
+		BytesColumnVector c = ColumnarResult.decode(this.buffer).getBytesColumn("c");
+		assertArrayEquals("foo".getBytes(StandardCharsets.UTF_8), c.getBytes(0));
+	}
+	
+	@Test
+	public void testGetNonexistentColumn() {
+		assertNull(ColumnarResult.decode(this.buffer).getColumn("z"));
+	}
+	
+	@Test(expected = SqlTypeMismatchException.class)
+	public void testGetWrongType() {
+		ColumnarResult.decode(this.buffer).getDoubleColumn("a");
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testDecodeTruncated() {
+		this.buffer.limit(this.buffer.limit() - 1);
+		ColumnarResult.decode(this.buffer);
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testDecodeUnknownKind() {
+		this.buffer.put(13, (byte) 9);
+		ColumnarResult.decode(this.buffer);
+	}
+}
@@ -0,0 +1,26 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertNull;
+
+import org.junit.Test;
+
+public class ExecuteResultTest {
+
+	@Test
+	public void testGetters() {
+		ExecuteResult result = new ExecuteResult(3, 42L, 1, "Rows matched: 3  Changed: 3  Warnings: 1");
+		assertEquals(3, result.getAffectedRows());
+		assertEquals(Long.valueOf(42), result.getLastInsertId());
+		assertEquals(1, result.getWarnings());
+		assertEquals("Rows matched: 3  Changed: 3  Warnings: 1", result.getInfo());
+	}
+	
+	@Test
+	public void testWithoutMysqlFields() {
+		ExecuteResult result = new ExecuteResult(1, null, 0, null);
+		assertEquals(1, result.getAffectedRows());
+		assertNull(result.getLastInsertId());
+		assertNull(result.getInfo());
+	}
+}
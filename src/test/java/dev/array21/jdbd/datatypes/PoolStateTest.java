@@ -0,0 +1,17 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertEquals;
+
+import org.junit.Test;
+
+public class PoolStateTest {
+
+	@Test
+	public void testGetters() {
+		PoolState state = new PoolState(5, 2, 10);
+		assertEquals(5, state.getConnections());
+		assertEquals(2, state.getIdleConnections());
+		assertEquals(3, state.getActiveConnections());
+		assertEquals(10, state.getMaxConnections());
+	}
+}
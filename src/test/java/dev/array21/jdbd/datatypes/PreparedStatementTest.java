@@ -0,0 +1,72 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertTrue;
+
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.OffsetDateTime;
+
+import org.junit.Test;
+
+public class PreparedStatementTest {
+
+	@Test
+	public void testAllBound() {
+		PreparedStatement statement = new PreparedStatement("INSERT INTO foo VALUES (?, ?)");
+		statement.bind(0, 1);
+		assertFalse(statement.allBound());
+		
+		statement.bind(1, "bar");
+		assertTrue(statement.allBound());
+	}
+	
+	@Test
+	public void testBindTemporal() {
+		PreparedStatement statement = new PreparedStatement("INSERT INTO foo VALUES (?, ?, ?, ?, ?, ?)");
+		statement.bind(0, LocalDate.of(2021, 2, 3));
+		statement.bind(1, LocalDateTime.of(2021, 2, 3, 4, 5, 6));
+		statement.bind(2, Instant.EPOCH);
+		statement.bind(3, OffsetDateTime.now());
+		statement.bind(4, Duration.ofMinutes(-90));
+		statement.bind(5, LocalTime.NOON);
+		assertTrue(statement.allBound());
+	}
+	
+	@Test
+	public void testFetchSize() {
+		PreparedStatement statement = new PreparedStatement("SELECT * FROM foo");
+		assertEquals(0, statement.getFetchSize());
+		
+		statement.setFetchSize(500);
+		assertEquals(500, statement.getFetchSize());
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testNegativeFetchSize() {
+		new PreparedStatement("SELECT * FROM foo").setFetchSize(-1);
+	}
+	
+	@Test
+	public void testIsBoundBy() {
+		PreparedStatement statement = new PreparedStatement("INSERT INTO foo VALUES (?, ?)");
+		assertTrue(statement.isBoundBy(new SqlParameter[] { new SqlParameter(1L), new SqlParameter() }));
+	}
+	
+	@Test
+	public void testIsBoundByWrongLength() {
+		PreparedStatement statement = new PreparedStatement("INSERT INTO foo VALUES (?, ?)");
+		assertFalse(statement.isBoundBy(new SqlParameter[] { new SqlParameter(1L) }));
+		assertFalse(statement.isBoundBy(null));
+	}
+	
+	@Test
+	public void testIsBoundByNullParameter() {
+		PreparedStatement statement = new PreparedStatement("INSERT INTO foo VALUES (?, ?)");
+		assertFalse(statement.isBoundBy(new SqlParameter[] { new SqlParameter(1L), null }));
+	}
+}
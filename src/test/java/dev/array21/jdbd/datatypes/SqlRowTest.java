@@ -4,6 +4,15 @@ import static org.junit.Assert.assertArrayEquals;
 import static org.junit.Assert.assertEquals;
 import static org.junit.Assert.assertNull;
 
+import java.time.Duration;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.time.LocalDateTime;
+import java.time.LocalTime;
+import java.time.OffsetDateTime;
+import java.time.OffsetTime;
+import java.time.ZoneOffset;
+
 import org.junit.Before;
 import org.junit.Test;
 
@@ -17,8 +26,8 @@ public class SqlRowTest {
 	public void setup() {
 		this.row = new SqlRow(
 				new String[] { "a", "b", "c", "d", "e" },
-				new Object[] { "baz", 1l, 2.5d, false, new Byte[] { 0x0 }},
-				new Class<?>[] { String.class, Long.class, Double.class, Boolean.class, Byte[].class });
+				new Object[] { "baz", 1l, 2.5d, false, new byte[] { 0x0 }},
+				new Class<?>[] { String.class, Long.class, Double.class, Boolean.class, byte[].class });
 	}
 	
 	@Test(expected = IllegalArgumentException.class)
@@ -48,7 +57,53 @@ public class SqlRowTest {
 	
 	@Test
 	public void testGetByteArray() {
-		assertArrayEquals(new Byte[] { 0x0 }, this.row.getBytes("e"));
+		assertArrayEquals(new byte[] { 0x0 }, this.row.getBytes("e"));
+	}
+	
+	@Test
+	@SuppressWarnings("deprecation")
+	public void testGetBoxedByteArray() {
+		assertArrayEquals(new Byte[] { 0x0 }, this.row.getBoxedBytes("e"));
+		assertNull(this.row.getBoxedBytes("z"));
+	}
+	
+	@Test
+	public void testGetTemporal() {
+		SqlRow row = new SqlRow(
+				new String[] { "date", "datetime", "timestamp", "time" },
+				new Object[] { LocalDate.of(2021, 2, 3), LocalDateTime.of(2021, 2, 3, 4, 5, 6, 7000), Instant.ofEpochSecond(1612325106L), Duration.ofHours(-1) },
+				new Class<?>[] { LocalDate.class, LocalDateTime.class, Instant.class, Duration.class });
+		
+		assertEquals(LocalDate.of(2021, 2, 3), row.getLocalDate("date"));
+		assertEquals(LocalDateTime.of(2021, 2, 3, 4, 5, 6, 7000), row.getLocalDateTime("datetime"));
+		assertEquals(Instant.ofEpochSecond(1612325106L), row.getInstant("timestamp"));
+		assertEquals(OffsetDateTime.of(2021, 2, 3, 4, 5, 6, 0, ZoneOffset.UTC), row.getOffsetDateTime("timestamp"));
+		assertEquals(Duration.ofHours(-1), row.getDuration("time"));
+	}
+	
+	@Test
+	public void testGetLocalTimeFromDuration() {
+		SqlRow row = new SqlRow(new String[] { "time", "null" }, new Object[] { Duration.ofSeconds(3723, 500), null }, new Class<?>[] { Duration.class, Duration.class });
+		assertEquals(LocalTime.of(1, 2, 3, 500), row.getLocalTime("time"));
+		assertNull(row.getLocalTime("null"));
+		assertNull(row.getLocalTime("z"));
+	}
+	
+	@Test
+	public void testGetTime() {
+		SqlRow row = new SqlRow(
+				new String[] { "time", "timetz" },
+				new Object[] { LocalTime.of(1, 2, 3), OffsetTime.of(1, 2, 3, 0, ZoneOffset.ofHours(2)) },
+				new Class<?>[] { LocalTime.class, OffsetTime.class });
+		
+		assertEquals(LocalTime.of(1, 2, 3), row.getLocalTime("time"));
+		assertEquals(OffsetTime.of(1, 2, 3, 0, ZoneOffset.ofHours(2)), row.getOffsetTime("timetz"));
+	}
+	
+	@Test(expected = SqlTypeMismatchException.class)
+	public void testGetLocalTimeOutsideDay() {
+		SqlRow row = new SqlRow(new String[] { "time" }, new Object[] { Duration.ofHours(25) }, new Class<?>[] { Duration.class });
+		row.getLocalTime("time");
 	}
 	
 	@Test
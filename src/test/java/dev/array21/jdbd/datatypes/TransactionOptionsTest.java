@@ -0,0 +1,31 @@
+package dev.array21.jdbd.datatypes;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class TransactionOptionsTest {
+
+	@Test
+	public void testDefaults() {
+		TransactionOptions options = new TransactionOptions();
+		assertNull(options.getIsolationLevel());
+		assertFalse(options.isReadOnly());
+		assertFalse(options.isDeferrable());
+	}
+	
+	@Test
+	public void testSetters() {
+		TransactionOptions options = new TransactionOptions()
+				.setIsolationLevel(IsolationLevel.SERIALIZABLE)
+				.setReadOnly(true)
+				.setDeferrable(true);
+		
+		assertEquals(IsolationLevel.SERIALIZABLE, options.getIsolationLevel());
+		assertTrue(options.isReadOnly());
+		assertTrue(options.isDeferrable());
+	}
+}
@@ -0,0 +1,152 @@
+package dev.array21.jdbd.drivers;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertSame;
+import static org.junit.Assert.assertTrue;
+
+import java.util.Arrays;
+import java.util.NoSuchElementException;
+
+import org.junit.Test;
+
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+import dev.array21.jdbd.exceptions.UncheckedSqlException;
+
+public class BufferedResultSetTest {
+
+	/**
+	 * Result set over a fixed number of rows, which records how it is used
+	 */
+	private static class FakeResultSet extends BufferedResultSet {
+		private final SqlRow[] rows;
+		private int position = 0;
+		private int fetches = 0;
+		private int releases = 0;
+		private SqlException error;
+		
+		FakeResultSet(int count) {
+			this(count, 0);
+		}
+		
+		FakeResultSet(int count, int fetchSize) {
+			super(fetchSize);
+			this.rows = new SqlRow[count];
+			for(int i = 0; i < count; i++) {
+				this.rows[i] = new SqlRow(new String[] { "i" }, new Object[] { (long) i }, new Class<?>[] { Long.class }) {};
+			}
+		}
+		
+		@Override
+		protected SqlRow[] fetchRows(int count) throws SqlException {
+			if(this.error != null) {
+				throw this.error;
+			}
+			
+			this.fetches++;
+			int end = Math.min(this.rows.length, this.position + count);
+			SqlRow[] result = Arrays.copyOfRange(this.rows, this.position, end);
+			this.position = end;
+			return result;
+		}
+
+		@Override
+		protected void release() throws SqlException {
+			this.releases++;
+		}
+	}
+	
+	@Test
+	public void testIterateAll() {
+		FakeResultSet resultSet = new FakeResultSet(BufferedResultSet.DEFAULT_FETCH_SIZE + 5);
+		int count = 0;
+		while(resultSet.hasNext()) {
+			assertSame(resultSet.rows[count], resultSet.next());
+			count++;
+		}
+		
+		assertEquals(resultSet.rows.length, count);
+		assertEquals(2, resultSet.fetches);
+		assertEquals(1, resultSet.releases);
+	}
+	
+	@Test
+	public void testIterateWithFetchSize() {
+		FakeResultSet resultSet = new FakeResultSet(10, 3);
+		int count = 0;
+		while(resultSet.hasNext()) {
+			resultSet.next();
+			count++;
+		}
+		
+		assertEquals(10, count);
+		assertEquals(4, resultSet.fetches);
+	}
+	
+	@Test
+	public void testFetch() throws SqlException {
+		FakeResultSet resultSet = new FakeResultSet(5);
+		assertEquals(3, resultSet.fetch(3).length);
+		assertEquals(0, resultSet.releases);
+		
+		assertEquals(2, resultSet.fetch(3).length);
+		assertEquals(1, resultSet.releases);
+		assertEquals(0, resultSet.fetch(3).length);
+	}
+	
+	@Test
+	public void testFetchAfterNext() throws SqlException {
+		FakeResultSet resultSet = new FakeResultSet(5);
+		assertSame(resultSet.rows[0], resultSet.next());
+		
+		SqlRow[] rows = resultSet.fetch(2);
+		assertEquals(2, rows.length);
+		assertSame(resultSet.rows[1], rows[0]);
+		assertSame(resultSet.rows[2], rows[1]);
+	}
+	
+	@Test
+	public void testCloseReleasesOnce() throws SqlException {
+		FakeResultSet resultSet = new FakeResultSet(5);
+		resultSet.fetch(1);
+		resultSet.close();
+		resultSet.close();
+		assertTrue(resultSet.isClosed());
+		assertEquals(1, resultSet.releases);
+	}
+	
+	@Test
+	public void testCloseAfterExhausted() throws SqlException {
+		FakeResultSet resultSet = new FakeResultSet(0);
+		assertFalse(resultSet.hasNext());
+		resultSet.close();
+		assertEquals(1, resultSet.releases);
+	}
+	
+	@Test(expected = NoSuchElementException.class)
+	public void testNextWhenExhausted() {
+		new FakeResultSet(0).next();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testUseAfterClose() throws SqlException {
+		FakeResultSet resultSet = new FakeResultSet(5);
+		resultSet.close();
+		resultSet.hasNext();
+	}
+	
+	@Test
+	public void testErrorIsUnchecked() {
+		FakeResultSet resultSet = new FakeResultSet(5);
+		resultSet.error = new SqlException("foo");
+		try {
+			resultSet.hasNext();
+		} catch(UncheckedSqlException e) {
+			assertSame(resultSet.error, e.getCause());
+			return;
+		}
+		
+		throw new AssertionError("Expected an UncheckedSqlException");
+	}
+}
@@ -0,0 +1,38 @@
+package dev.array21.jdbd.drivers;
+
+import java.io.IOException;
+
+import org.junit.Test;
+
+public class MysqlDriverFactoryTest {
+
+	@Test(expected = IllegalStateException.class)
+	public void testHostUnset() throws IOException {
+		new MysqlDriverFactory().setDatabase("foo").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testDatabaseUnset() throws IOException {
+		new MysqlDriverFactory().setHost("localhost").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testUrlWithHost() throws IOException {
+		new MysqlDriverFactory().setUrl("mysql://foo@localhost/bar").setHost("localhost").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testInvalidPort() throws IOException {
+		new MysqlDriverFactory().setHost("localhost").setDatabase("foo").setPort(0).build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testInvalidPoolSize() throws IOException {
+		new MysqlDriverFactory().setHost("localhost").setDatabase("foo").setMaxPoolSize(2).setMinPoolSize(3).build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testEmptyLocalInfilePath() throws IOException {
+		new MysqlDriverFactory().setHost("localhost").setDatabase("foo").setLocalInfilePaths("/tmp", "").build();
+	}
+}
@@ -0,0 +1,164 @@
+package dev.array21.jdbd.drivers;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertTrue;
+
+import java.util.ArrayList;
+import java.util.List;
+
+import org.junit.Test;
+
+import dev.array21.jdbd.Transaction;
+import dev.array21.jdbd.datatypes.ExecuteResult;
+import dev.array21.jdbd.datatypes.PreparedStatement;
+import dev.array21.jdbd.datatypes.SqlParameter;
+import dev.array21.jdbd.datatypes.SqlRow;
+import dev.array21.jdbd.exceptions.SqlException;
+
+public class NestedTransactionTest {
+	
+	/**
+	 * Transaction which records the savepoint statements run on it
+	 */
+	private static class RecordingTransaction implements Transaction {
+		private final List<String> statements = new ArrayList<>();
+		private boolean active = true;
+		
+		@Override
+		public SqlRow[] query(PreparedStatement statement) throws SqlException {
+			this.statements.add("QUERY");
+			return new SqlRow[0];
+		}
+
+		@Override
+		public ExecuteResult execute(PreparedStatement statement) throws SqlException {
+			this.statements.add("EXECUTE");
+			return null;
+		}
+
+		@Override
+		public SqlRow[] executeGeneratedKeys(PreparedStatement statement, String... keyColumns) throws SqlException {
+			this.statements.add("EXECUTE GENERATED KEYS");
+			return new SqlRow[0];
+		}
+
+		@Override
+		public long[] executeBatch(PreparedStatement statement, List<SqlParameter[]> parameterSets) throws SqlException {
+			this.statements.add("EXECUTE BATCH");
+			return new long[parameterSets.size()];
+		}
+
+		@Override
+		public void commit() throws SqlException {
+			this.active = false;
+		}
+
+		@Override
+		public void rollback() throws SqlException {
+			this.active = false;
+		}
+
+		@Override
+		public boolean isActive() {
+			return this.active;
+		}
+
+		@Override
+		public Transaction beginTransaction() throws SqlException {
+			return NestedTransaction.begin(this, this);
+		}
+
+		@Override
+		public void savepoint(String name) throws SqlException {
+			this.statements.add("SAVEPOINT");
+		}
+
+		@Override
+		public void releaseSavepoint(String name) throws SqlException {
+			this.statements.add("RELEASE");
+		}
+
+		@Override
+		public void rollbackToSavepoint(String name) throws SqlException {
+			this.statements.add("ROLLBACK TO");
+		}
+
+		@Override
+		public void close() throws SqlException {
+			this.active = false;
+		}
+	}
+	
+	@Test
+	public void testCommitReleasesSavepoint() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		Transaction nested = root.beginTransaction();
+		nested.execute(new PreparedStatement("SELECT 1"));
+		nested.commit();
+		
+		assertFalse(nested.isActive());
+		assertTrue(root.isActive());
+		assertEquals(List.of("SAVEPOINT", "EXECUTE", "RELEASE"), root.statements);
+	}
+	
+	@Test
+	public void testExecuteGeneratedKeysRunsOnRoot() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		Transaction nested = root.beginTransaction();
+		nested.executeGeneratedKeys(new PreparedStatement("INSERT INTO foo VALUES (1)"));
+		
+		assertEquals(List.of("SAVEPOINT", "EXECUTE GENERATED KEYS"), root.statements);
+	}
+	
+	@Test
+	public void testCloseRollsBackToSavepoint() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		try(Transaction nested = root.beginTransaction()) {
+			nested.execute(new PreparedStatement("SELECT 1"));
+		}
+		
+		assertTrue(root.isActive());
+		assertEquals(List.of("SAVEPOINT", "EXECUTE", "ROLLBACK TO", "RELEASE"), root.statements);
+	}
+	
+	@Test
+	public void testCloseAfterCommitDoesNothing() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		try(Transaction nested = root.beginTransaction()) {
+			nested.commit();
+		}
+		
+		assertEquals(List.of("SAVEPOINT", "RELEASE"), root.statements);
+	}
+	
+	@Test
+	public void testNestedInNested() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		Transaction outer = root.beginTransaction();
+		Transaction inner = outer.beginTransaction();
+		inner.commit();
+		outer.commit();
+		
+		assertEquals(List.of("SAVEPOINT", "SAVEPOINT", "RELEASE", "RELEASE"), root.statements);
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testInactiveWhenParentFinished() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		Transaction outer = root.beginTransaction();
+		Transaction inner = outer.beginTransaction();
+		outer.rollback();
+		
+		assertFalse(inner.isActive());
+		inner.execute(new PreparedStatement("SELECT 1"));
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testCommitTwice() throws SqlException {
+		RecordingTransaction root = new RecordingTransaction();
+		Transaction nested = root.beginTransaction();
+		nested.commit();
+		nested.commit();
+	}
+}
@@ -0,0 +1,38 @@
+package dev.array21.jdbd.drivers;
+
+import java.io.IOException;
+
+import org.junit.Test;
+
+public class PostgreSqlDriverFactoryTest {
+
+	@Test(expected = IllegalStateException.class)
+	public void testHostUnset() throws IOException {
+		new PostgreSqlDriverFactory().setDatabase("foo").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testDatabaseUnset() throws IOException {
+		new PostgreSqlDriverFactory().setHost("localhost").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testUrlWithHost() throws IOException {
+		new PostgreSqlDriverFactory().setUrl("postgres://foo@localhost/bar").setHost("localhost").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testHostWithSocket() throws IOException {
+		new PostgreSqlDriverFactory().setHost("localhost").setSocket("/var/run/postgresql").setDatabase("foo").build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testInvalidPort() throws IOException {
+		new PostgreSqlDriverFactory().setHost("localhost").setDatabase("foo").setPort(0).build();
+	}
+	
+	@Test(expected = IllegalStateException.class)
+	public void testInvalidPoolSize() throws IOException {
+		new PostgreSqlDriverFactory().setHost("localhost").setDatabase("foo").setMaxPoolSize(2).setMinPoolSize(3).build();
+	}
+}
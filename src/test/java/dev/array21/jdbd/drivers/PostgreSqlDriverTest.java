@@ -0,0 +1,43 @@
+package dev.array21.jdbd.drivers;
+
+import static org.junit.Assert.assertEquals;
+
+import org.junit.Test;
+
+public class PostgreSqlDriverTest {
+
+	@Test
+	public void testReturningAll() {
+		assertEquals("INSERT INTO foo VALUES (1)\nRETURNING *", PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1)", new String[0]));
+	}
+	
+	@Test
+	public void testReturningKeyColumns() {
+		assertEquals("INSERT INTO foo VALUES (1)\nRETURNING \"id\", \"Other\"", PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1)", new String[] { "id", "Other" }));
+	}
+	
+	@Test
+	public void testReturningQuotedColumn() {
+		assertEquals("INSERT INTO foo VALUES (1)\nRETURNING \"a\"\"b\"", PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1)", new String[] { "a\"b" }));
+	}
+	
+	@Test
+	public void testReturningTrailingSemicolon() {
+		assertEquals("INSERT INTO foo VALUES (1)\nRETURNING *", PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1) ; \n", new String[0]));
+	}
+	
+	@Test
+	public void testReturningTrailingComment() {
+		assertEquals("INSERT INTO foo VALUES (1) -- comment\nRETURNING *", PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1) -- comment", new String[0]));
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testReturningEmptyColumn() {
+		PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1)", new String[] { "" });
+	}
+	
+	@Test(expected = IllegalArgumentException.class)
+	public void testReturningNullColumn() {
+		PostgreSqlDriver.withReturningClause("INSERT INTO foo VALUES (1)", new String[] { null });
+	}
+}
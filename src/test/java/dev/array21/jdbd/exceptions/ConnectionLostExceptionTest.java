@@ -0,0 +1,22 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class ConnectionLostExceptionTest {
+
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new ConnectionLostException("foo").getMessage());
+	}
+	
+	@Test
+	public void testDatabaseErrorConstructor() {
+		ConnectionLostException e = new ConnectionLostException("foo", "08006", null, null, null, null, null, null);
+		assertTrue(e instanceof SqlException);
+		assertEquals("foo", e.getMessage());
+		assertEquals("08006", e.getSqlState());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class DeadlockExceptionTest {
+
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new DeadlockException("foo").getMessage());
+	}
+	
+	@Test
+	public void testDatabaseErrorConstructor() {
+		DeadlockException e = new DeadlockException("foo", "40P01", 1213, null, null, null, null, null);
+		assertTrue(e instanceof SqlException);
+		assertEquals("foo", e.getMessage());
+		assertEquals("40P01", e.getSqlState());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertSame;
+
+import org.junit.Test;
+
+public class DriverExceptionTest {
+
+	@Test
+	public void testEmptyConstructor() {
+		assertEquals(null, new DriverException().getMessage());
+	}
+	
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new DriverException("foo").getMessage());
+	}
+	
+	@Test
+	public void testCauseConstructor() {
+		Throwable cause = new RuntimeException("bar");
+		DriverException e = new DriverException("foo", cause);
+		assertEquals("foo", e.getMessage());
+		assertSame(cause, e.getCause());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class ForeignKeyViolationExceptionTest {
+
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new ForeignKeyViolationException("foo").getMessage());
+	}
+	
+	@Test
+	public void testDatabaseErrorConstructor() {
+		ForeignKeyViolationException e = new ForeignKeyViolationException("foo", "23503", 1452, null, null, null, null, null);
+		assertTrue(e instanceof SqlException);
+		assertEquals("foo", e.getMessage());
+		assertEquals("23503", e.getSqlState());
+	}
+}
\ No newline at end of file
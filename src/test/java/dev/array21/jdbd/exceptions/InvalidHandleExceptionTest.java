@@ -0,0 +1,27 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertSame;
+
+import org.junit.Test;
+
+public class InvalidHandleExceptionTest {
+
+	@Test
+	public void testEmptyConstructor() {
+		assertEquals(null, new InvalidHandleException().getMessage());
+	}
+	
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new InvalidHandleException("foo").getMessage());
+	}
+	
+	@Test
+	public void testCauseConstructor() {
+		Throwable cause = new RuntimeException("bar");
+		InvalidHandleException e = new InvalidHandleException("foo", cause);
+		assertEquals("foo", e.getMessage());
+		assertSame(cause, e.getCause());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertSame;
+
+import org.junit.Test;
+
+public class SqlExceptionTest {
+
+	@Test
+	public void testEmptyConstructor() {
+		assertEquals(null, new SqlException().getMessage());
+	}
+	
+	@Test
+	public void testMessageConstructor() {
+		SqlException e = new SqlException("foo");
+		assertEquals("foo", e.getMessage());
+		assertNull(e.getSqlState());
+		assertNull(e.getVendorCode());
+	}
+	
+	@Test
+	public void testCauseConstructor() {
+		Throwable cause = new RuntimeException("bar");
+		SqlException e = new SqlException("foo", cause);
+		assertEquals("foo", e.getMessage());
+		assertSame(cause, e.getCause());
+	}
+	
+	@Test
+	public void testDatabaseErrorConstructor() {
+		SqlException e = new SqlException("foo", "23505", 1062, "detail", "hint", "users_pkey", "users", "id");
+		assertEquals("foo", e.getMessage());
+		assertEquals("23505", e.getSqlState());
+		assertEquals(Integer.valueOf(1062), e.getVendorCode());
+		assertEquals("detail", e.getDetail());
+		assertEquals("hint", e.getHint());
+		assertEquals("users_pkey", e.getConstraint());
+		assertEquals("users", e.getTable());
+		assertEquals("id", e.getColumn());
+	}
+}
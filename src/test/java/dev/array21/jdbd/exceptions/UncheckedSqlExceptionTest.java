@@ -0,0 +1,25 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertSame;
+
+import org.junit.Test;
+
+public class UncheckedSqlExceptionTest {
+
+	@Test
+	public void testCauseConstructor() {
+		SqlException cause = new SqlException("bar");
+		UncheckedSqlException e = new UncheckedSqlException(cause);
+		assertEquals("bar", e.getMessage());
+		assertSame(cause, e.getCause());
+	}
+	
+	@Test
+	public void testMessageCauseConstructor() {
+		SqlException cause = new SqlException("bar");
+		UncheckedSqlException e = new UncheckedSqlException("foo", cause);
+		assertEquals("foo", e.getMessage());
+		assertSame(cause, e.getCause());
+	}
+}
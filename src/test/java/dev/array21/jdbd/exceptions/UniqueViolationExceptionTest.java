@@ -0,0 +1,22 @@
+package dev.array21.jdbd.exceptions;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertTrue;
+
+import org.junit.Test;
+
+public class UniqueViolationExceptionTest {
+
+	@Test
+	public void testMessageConstructor() {
+		assertEquals("foo", new UniqueViolationException("foo").getMessage());
+	}
+	
+	@Test
+	public void testDatabaseErrorConstructor() {
+		UniqueViolationException e = new UniqueViolationException("foo", "23505", 1062, null, null, null, null, null);
+		assertTrue(e instanceof SqlException);
+		assertEquals("foo", e.getMessage());
+		assertEquals("23505", e.getSqlState());
+	}
+}
\ No newline at end of file